@@ -0,0 +1,73 @@
+//! Time the solver with criterion: `cargo bench --bench solve`
+//! The size of the grid is chosen at compile time, so each size has its own benchmarks, for instance:
+//! `cargo bench --bench solve --no-default-features --features size-4x4`
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use sudoku_rs::Grid;
+
+/// Solved by backtracking alone in a reasonable time, but with many dead-ends
+#[cfg(feature = "size-9x9")]
+const HARD_PUZZLE: &str =
+    "8..........36......7..9.2...5...7.......457.....1...3...1....68..85...1..9....4..";
+
+/// So few givens that there are far too many solutions to count them all
+#[cfg(feature = "size-9x9")]
+const NEARLY_EMPTY: &str =
+    "1....7.9................................5................................3.....8.";
+
+#[cfg(feature = "size-9x9")]
+fn bench_9x9(c: &mut Criterion) {
+    let hard = HARD_PUZZLE.parse::<Grid>().unwrap();
+    let mut group = c.benchmark_group("every solution of a hard puzzle");
+    // Backtracking alone takes about 100ms per run
+    group.sample_size(20);
+    group.bench_function("backtracking", |b| {
+        b.iter(|| assert_eq!(black_box(&hard).count_solutions(None), 1))
+    });
+    group.bench_function("forward checking", |b| {
+        b.iter(|| assert_eq!(black_box(&hard).try_solve_fc().count(), 1))
+    });
+    group.bench_function("MRV", |b| {
+        b.iter(|| assert_eq!(black_box(&hard).try_solve_mrv().count(), 1))
+    });
+    group.bench_function("DLX", |b| {
+        b.iter(|| assert_eq!(black_box(&hard).count_solutions_dlx(None), 1))
+    });
+    group.finish();
+
+    let nearly_empty = NEARLY_EMPTY.parse::<Grid>().unwrap();
+    c.bench_function("first 10000 solutions of a nearly empty grid", |b| {
+        b.iter(|| {
+            assert_eq!(
+                black_box(&nearly_empty).count_solutions(Some(10_000)),
+                10_000
+            )
+        })
+    });
+}
+
+#[cfg(feature = "size-4x4")]
+fn bench_4x4(c: &mut Criterion) {
+    let empty = "................".parse::<Grid>().unwrap();
+    c.bench_function("every solution of the empty 4x4 grid", |b| {
+        b.iter(|| assert_eq!(black_box(&empty).try_solve().count(), 288))
+    });
+}
+
+#[cfg(feature = "size-16x16")]
+fn bench_16x16(c: &mut Criterion) {
+    let empty = Grid::empty();
+    c.bench_function("first solution of the empty 16x16 grid", |b| {
+        b.iter(|| black_box(&empty).solve().unwrap())
+    });
+}
+
+#[cfg(feature = "size-9x9")]
+criterion_group!(benches, bench_9x9);
+#[cfg(feature = "size-4x4")]
+criterion_group!(benches, bench_4x4);
+#[cfg(feature = "size-16x16")]
+criterion_group!(benches, bench_16x16);
+criterion_main!(benches);
@@ -0,0 +1,26 @@
+//! The solver in a `#![no_std]` crate, like firmware for a microcontroller: only an allocator is needed
+//! This is built along with the tests; to check the library itself on a target without std:
+//! `cargo build --lib --no-default-features --features size-9x9 --target thumbv7em-none-eabihf`
+#![no_std]
+
+use core::fmt::{self, Write};
+
+use sudoku_rs::{Grid, NB_CELL};
+
+/// Solve a puzzle written on a single line, and write its first solution on a single line into [out]
+/// Return false if the puzzle can not be parsed, or has no solution
+pub fn solve_line(puzzle: &str, out: &mut [u8; NB_CELL]) -> bool {
+    let Some(solution) = puzzle.parse::<Grid>().ok().and_then(|grid| grid.solve()) else {
+        return false;
+    };
+    *out = solution.grid().to_line_bytes();
+    true
+}
+
+/// Draw the solution of [puzzle] with [Grid]'s [fmt::Display], into a writer without heap, like a serial port
+pub fn print_solution(puzzle: &Grid, out: &mut impl Write) -> fmt::Result {
+    match puzzle.solve() {
+        Some(solution) => write!(out, "{}", solution),
+        None => out.write_str("no solution\n"),
+    }
+}
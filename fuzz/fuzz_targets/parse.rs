@@ -0,0 +1,42 @@
+//! Feed arbitrary bytes to every parser of grids: each must return Ok or Err without panicking,
+//! and any grid it returns must be valid
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sudoku_rs::{parse_cells, Grid, NB_CELL};
+
+/// A grid returned by a parser must never hold contradicting digits, and must survive a round trip
+fn check(grid: Grid) {
+    assert!(grid.is_valid(), "{:?}", grid);
+    assert_eq!(grid.to_line_string().parse::<Grid>(), Ok(grid));
+}
+
+fuzz_target!(|data: &[u8]| {
+    if let Some(array) = data.first_chunk::<NB_CELL>() {
+        if let Ok(grid) = Grid::try_from(*array) {
+            check(grid);
+        }
+    }
+
+    let Ok(s) = std::str::from_utf8(data) else {
+        return;
+    };
+    if let Ok(grid) = s.parse::<Grid>() {
+        check(grid);
+    }
+    if let Ok(grid) = Grid::parse_auto(s) {
+        check(grid);
+    }
+    if let Ok(grid) = Grid::from_rows(&s.lines().collect::<Vec<_>>()) {
+        check(grid);
+    }
+    if let Ok(grid) = Grid::from_sdk(s) {
+        check(grid);
+    }
+    if let Ok(grid) = Grid::from_ss(s) {
+        check(grid);
+    }
+    // Contradicting digits are allowed here, but only digits of this grid size
+    let _ = parse_cells(s);
+});
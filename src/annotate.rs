@@ -0,0 +1,149 @@
+use crate::{Digit, Grid, SolvedGrid, NB_CELL};
+
+/// A cell of an [AnnotatedGrid], which knows where its digit comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnnotatedCell {
+    /// The digit was part of the puzzle
+    Given(Digit),
+    /// The digit was found by the solver
+    Solved(Digit),
+    /// The cell was left empty, like obstacles
+    Empty,
+}
+
+/// A grid, solved or not, laid over its puzzle, see [Grid::annotate]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnnotatedGrid {
+    cells: [AnnotatedCell; NB_CELL],
+}
+
+impl AnnotatedGrid {
+    /// Every cell, in row-major order
+    pub fn cells(&self) -> &[AnnotatedCell; NB_CELL] {
+        &self.cells
+    }
+
+    /// Which cells hold a digit of the puzzle, see [Grid::givens]
+    pub fn givens(&self) -> [bool; NB_CELL] {
+        self.cells
+            .map(|cell| matches!(cell, AnnotatedCell::Given(_)))
+    }
+}
+
+impl Grid {
+    /// Which cells hold a digit: on a puzzle, these are the clues, which a front-end should keep
+    /// the player from changing, and show apart from the digits filled in since
+    pub fn givens(&self) -> [bool; NB_CELL] {
+        self.data.map(|cell| cell.is_some())
+    }
+
+    /// Tag each digit of this grid, which may still have empty cells, as given by [puzzle]
+    /// or filled in since
+    /// PANIC if a digit of [puzzle] is not in this grid
+    pub fn annotate(&self, puzzle: &Grid) -> AnnotatedGrid {
+        let cells = core::array::from_fn(|pos| match (puzzle.data[pos], self.data[pos]) {
+            (Some(given), cell) => {
+                assert_eq!(
+                    Some(given),
+                    cell,
+                    "the grid does not match the puzzle at {}",
+                    pos
+                );
+                AnnotatedCell::Given(given)
+            }
+            (None, Some(d)) => AnnotatedCell::Solved(d),
+            (None, None) => AnnotatedCell::Empty,
+        });
+        AnnotatedGrid { cells }
+    }
+}
+
+impl SolvedGrid {
+    /// Tag each cell of the solution as given by [puzzle] or found by the solver, see [Grid::annotate]
+    /// PANIC if [puzzle] has a digit that differs from the solution
+    pub fn annotate(&self, puzzle: &Grid) -> AnnotatedGrid {
+        self.grid.annotate(puzzle)
+    }
+}
+
+// The fixtures are 4×4 grids
+#[cfg(all(test, feature = "size-4x4"))]
+mod test {
+    use crate::{AnnotatedCell, Digit, Grid};
+
+    #[test]
+    fn annotate_solution() {
+        #[rustfmt::skip]
+        let puzzle = Grid::from_u8s([
+            1, 2, 3, 4,
+            3, 4, 1, 2,
+            2, 1, 4, 3,
+            4, 3, 0, 0,
+        ]);
+        let annotated = puzzle.assert_solvable().unwrap().annotate(&puzzle);
+
+        assert_eq!(
+            annotated.cells()[0],
+            AnnotatedCell::Given(Digit::new(1).unwrap())
+        );
+        assert_eq!(
+            annotated.cells()[13],
+            AnnotatedCell::Given(Digit::new(3).unwrap())
+        );
+        assert_eq!(
+            annotated.cells()[14],
+            AnnotatedCell::Solved(Digit::new(2).unwrap())
+        );
+        assert_eq!(
+            annotated.cells()[15],
+            AnnotatedCell::Solved(Digit::new(1).unwrap())
+        );
+        assert_eq!(
+            annotated
+                .cells()
+                .iter()
+                .filter(|c| matches!(c, AnnotatedCell::Given(_)))
+                .count(),
+            14
+        );
+    }
+
+    #[test]
+    fn annotate_partially_solved_grid() {
+        let puzzle = Grid::from_hex_str("12.4..12.143...1").unwrap();
+        let mut grid = puzzle.clone();
+        grid.data[2] = Some(Digit::new(3).unwrap());
+
+        let annotated = grid.annotate(&puzzle);
+        assert_eq!(
+            annotated.cells()[1],
+            AnnotatedCell::Given(Digit::new(2).unwrap())
+        );
+        assert_eq!(
+            annotated.cells()[2],
+            AnnotatedCell::Solved(Digit::new(3).unwrap())
+        );
+        assert_eq!(annotated.cells()[4], AnnotatedCell::Empty);
+        assert_eq!(annotated.givens(), puzzle.givens());
+        assert_eq!(
+            puzzle.givens().iter().filter(|given| **given).count(),
+            puzzle.clue_count()
+        );
+        assert!(!puzzle.givens()[2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn annotate_without_given() {
+        let puzzle = Grid::from_hex_str("2...............").unwrap();
+        Grid::empty().annotate(&puzzle);
+    }
+
+    #[test]
+    #[should_panic]
+    fn annotate_with_other_puzzle() {
+        let solution = Grid::empty().assert_solvable().unwrap();
+        let other_puzzle = Grid::from_hex_str("2...............").unwrap();
+        solution.annotate(&other_puzzle);
+    }
+}
@@ -0,0 +1,151 @@
+use std::io::{self, Read, Write};
+
+use crate::{Digit, Grid, SolvedGrid, BLOCK_SIDE, NB_CELL};
+
+/// Each cell takes 4 bits, so two cells are packed in a byte
+const GRID_BYTES: usize = NB_CELL.div_ceil(2);
+
+/// Packed binary format for datasets of solved grids:
+/// - A one-byte header holding BLOCK_SIDE
+/// - Then every grid in turn, with each cell stored as `digit - 1` on 4 bits, first cell in the high bits
+impl SolvedGrid {
+    /// Fail with [io::ErrorKind::InvalidInput] before writing anything if a grid has empty cells,
+    /// as grids solved with obstacles do, since the format has no room for them
+    pub fn write_many(grids: &[SolvedGrid], mut writer: impl Write) -> io::Result<()> {
+        if let Some(index) = grids
+            .iter()
+            .position(|solved| solved.grid.data.contains(&None))
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("the grid {} has empty cells", index),
+            ));
+        }
+
+        writer.write_all(&[BLOCK_SIDE as u8])?;
+        for solved in grids {
+            let mut bytes = [0u8; GRID_BYTES];
+            for (pos, cell) in solved.grid.data.iter().enumerate() {
+                let nibble = cell.expect("checked above").value() - 1;
+                bytes[pos / 2] |= nibble << nibble_shift(pos);
+            }
+            writer.write_all(&bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Read back grids written by [SolvedGrid::write_many]
+    /// Fail with [io::ErrorKind::InvalidData] if the data was written for another grid size, or is not a solved grid
+    pub fn read_many(mut reader: impl Read) -> io::Result<Vec<SolvedGrid>> {
+        let mut header = [0u8];
+        reader.read_exact(&mut header)?;
+        if usize::from(header[0]) != BLOCK_SIDE {
+            return Err(invalid_data(format!(
+                "the grids have blocks of side {}, expected {}",
+                header[0], BLOCK_SIDE
+            )));
+        }
+
+        let mut grids = Vec::new();
+        loop {
+            let mut bytes = [0u8; GRID_BYTES];
+            let mut read = 0;
+            while read < GRID_BYTES {
+                match reader.read(&mut bytes[read..]) {
+                    Ok(0) => break,
+                    Ok(n) => read += n,
+                    Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            match read {
+                0 => return Ok(grids),
+                GRID_BYTES => grids.push(unpack(&bytes)?),
+                _ => return Err(io::ErrorKind::UnexpectedEof.into()),
+            }
+        }
+    }
+}
+
+/// Cells at an even position go in the high bits of their byte
+fn nibble_shift(pos: usize) -> u8 {
+    if pos.is_multiple_of(2) {
+        4
+    } else {
+        0
+    }
+}
+
+fn unpack(bytes: &[u8; GRID_BYTES]) -> io::Result<SolvedGrid> {
+    let mut grid = Grid::empty();
+    for pos in 0..NB_CELL {
+        let nibble = (bytes[pos / 2] >> nibble_shift(pos)) & 0xF;
+        let digit = Digit::new(nibble + 1)
+            .ok_or_else(|| invalid_data(format!("{} is not a digit", nibble + 1)))?;
+        grid.data[pos] = Some(digit);
+    }
+    if !grid.is_valid() {
+        return Err(invalid_data("the grid is not solved".to_string()));
+    }
+    Ok(SolvedGrid { grid })
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+// The fixtures are 4×4 grids
+#[cfg(all(test, feature = "size-4x4"))]
+mod test {
+    use std::io;
+
+    use super::GRID_BYTES;
+    use crate::{Grid, SolvedGrid, BLOCK_SIDE, NB_CELL};
+
+    #[test]
+    fn write_and_read_back_many_grids() {
+        let grids = Grid::empty().try_solve().collect::<Vec<_>>();
+
+        let mut bytes = Vec::new();
+        SolvedGrid::write_many(&grids, &mut bytes).unwrap();
+        assert_eq!(bytes.len(), 1 + grids.len() * GRID_BYTES);
+
+        let read = SolvedGrid::read_many(bytes.as_slice()).unwrap();
+        assert_eq!(read.len(), grids.len());
+        for (read, written) in read.iter().zip(&grids) {
+            assert_eq!(read.grid, written.grid);
+        }
+    }
+
+    #[test]
+    fn write_rejects_grids_with_obstacles() {
+        let mut obstacles = [false; NB_CELL];
+        obstacles[5] = true;
+        let with_obstacle = Grid::empty()
+            .try_solve_with_obstacles(obstacles)
+            .next()
+            .unwrap();
+        let grids = [Grid::empty().solve().unwrap(), with_obstacle];
+
+        let mut bytes = Vec::new();
+        let error = SolvedGrid::write_many(&grids, &mut bytes).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn read_rejects_bad_data() {
+        // Wrong grid size
+        assert!(SolvedGrid::read_many([BLOCK_SIDE as u8 + 1].as_slice()).is_err());
+        // Truncated grid
+        assert!(SolvedGrid::read_many([BLOCK_SIDE as u8, 0x01].as_slice()).is_err());
+        // Digits in contradiction
+        assert!(
+            SolvedGrid::read_many([BLOCK_SIDE as u8, 0, 0, 0, 0, 0, 0, 0, 0].as_slice()).is_err()
+        );
+        // No grid at all is fine
+        assert!(SolvedGrid::read_many([BLOCK_SIDE as u8].as_slice())
+            .unwrap()
+            .is_empty());
+    }
+}
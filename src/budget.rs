@@ -0,0 +1,122 @@
+use std::time::{Duration, Instant};
+
+use crate::{Grid, GridSolver, SolvedGrid, Timeout, STOP_CHECK_INTERVAL};
+
+/// A [GridSolver] whose every call to [Iterator::next] must return within a time budget, see [GridSolver::with_time_budget]
+pub struct BudgetedSolver<'a> {
+    solver: GridSolver<'a>,
+    budget: Duration,
+    /// How many progress the solver makes between two checks of the time
+    check_interval: usize,
+}
+
+impl Grid {
+    /// Return the first solution like [Grid::solve], or [Timeout] if it could not be found,
+    /// nor proven not to exist, within [budget]
+    /// To tune how often the time is checked, see [BudgetedSolver::with_check_interval]
+    pub fn solve_within(&self, budget: Duration) -> Result<Option<SolvedGrid>, Timeout> {
+        self.try_solve().with_time_budget(budget).next().transpose()
+    }
+}
+
+impl<'a> GridSolver<'a> {
+    /// Give each call to [Iterator::next] at most [budget] to find the next solution
+    /// When the budget is exceeded, [Timeout] is returned instead of a solution,
+    /// and the next call resumes the search where it stopped, with a fresh budget
+    pub fn with_time_budget(self, budget: Duration) -> BudgetedSolver<'a> {
+        BudgetedSolver {
+            solver: self,
+            budget,
+            check_interval: STOP_CHECK_INTERVAL,
+        }
+    }
+}
+
+impl BudgetedSolver<'_> {
+    /// Check the time once every [check_interval] progress of the search, instead of once every 1024
+    /// Checking more often makes the budget more accurate, but slows the search down
+    /// PANIC if [check_interval] is 0
+    pub fn with_check_interval(mut self, check_interval: usize) -> Self {
+        assert!(check_interval > 0, "the check interval must not be 0");
+        self.check_interval = check_interval;
+        self
+    }
+}
+
+impl Iterator for BudgetedSolver<'_> {
+    type Item = Result<SolvedGrid, Timeout>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let deadline = Instant::now() + self.budget;
+        self.solver
+            .next_or_stop(self.check_interval, || Instant::now() >= deadline)
+            .transpose()
+    }
+}
+
+// The fixtures are 4×4 grids
+#[cfg(all(test, feature = "size-4x4"))]
+mod test {
+    use std::time::Duration;
+
+    use crate::{Grid, Timeout};
+
+    #[test]
+    fn zero_budget_times_out() {
+        let grid = Grid::empty();
+        let mut solver = grid.try_solve().with_time_budget(Duration::ZERO);
+
+        assert_eq!(solver.next().unwrap().unwrap_err(), Timeout);
+        assert_eq!(solver.next().unwrap().unwrap_err(), Timeout);
+    }
+
+    #[test]
+    fn solve_within() {
+        let grid = Grid::from_hex_str("1..4.........2..").unwrap();
+        assert_eq!(
+            grid.solve_within(Duration::from_secs(60))
+                .unwrap()
+                .unwrap()
+                .grid,
+            grid.solve().unwrap().grid
+        );
+        assert_eq!(grid.solve_within(Duration::ZERO).unwrap_err(), Timeout);
+
+        let unsolvable = Grid::from_hex_str("12.....3........").unwrap();
+        assert!(matches!(
+            unsolvable.solve_within(Duration::from_secs(60)),
+            Ok(None)
+        ));
+    }
+
+    #[test]
+    fn check_time_at_every_progress() {
+        let grid = Grid::empty();
+        let solver = grid
+            .try_solve()
+            .with_time_budget(Duration::from_secs(60))
+            .with_check_interval(1);
+
+        let solutions: Result<Vec<_>, Timeout> = solver.collect();
+        assert_eq!(solutions.unwrap().len(), grid.try_solve().count());
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_check_interval() {
+        let grid = Grid::empty();
+        let _ = grid
+            .try_solve()
+            .with_time_budget(Duration::ZERO)
+            .with_check_interval(0);
+    }
+
+    #[test]
+    fn large_budget_finds_all_solutions() {
+        let grid = Grid::empty();
+        let solver = grid.try_solve().with_time_budget(Duration::from_secs(60));
+
+        let solutions: Result<Vec<_>, Timeout> = solver.collect();
+        assert_eq!(solutions.unwrap().len(), grid.try_solve().count());
+    }
+}
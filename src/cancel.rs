@@ -0,0 +1,73 @@
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use crate::{Grid, GridSolver, SolvedGrid};
+
+/// The search was stopped because its cancellation flag was set, see [Grid::solve_cancellable]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+/// How many progress the solver makes between two checks of the cancellation flag
+/// Reading an atomic is cheap, but still not free in the hot loop of the search
+const CANCEL_CHECK_INTERVAL: usize = 4096;
+
+impl Grid {
+    /// Return the first solution like [Grid::solve], or [Cancelled] once [cancel] is set, possibly by another thread
+    /// Meant for searches which must be stoppable on demand, like behind the "stop" button of a GUI
+    pub fn solve_cancellable(&self, cancel: &AtomicBool) -> Result<Option<SolvedGrid>, Cancelled> {
+        self.try_solve().next_cancellable(cancel)
+    }
+}
+
+impl GridSolver<'_> {
+    /// Like [Iterator::next], but give up with [Cancelled] once [cancel] is set, possibly by another thread
+    /// The search can be resumed later by calling this function again
+    pub fn next_cancellable(
+        &mut self,
+        cancel: &AtomicBool,
+    ) -> Result<Option<SolvedGrid>, Cancelled> {
+        self.next_or_stop(CANCEL_CHECK_INTERVAL, || cancel.load(Ordering::Relaxed))
+            .map_err(|_| Cancelled)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::sync::atomic::AtomicBool;
+
+    use crate::{Cancelled, Grid};
+
+    #[test]
+    fn cancelled_before_start() {
+        let cancel = AtomicBool::new(true);
+        assert_eq!(
+            Grid::empty().solve_cancellable(&cancel).unwrap_err(),
+            Cancelled
+        );
+    }
+
+    #[test]
+    fn not_cancelled() {
+        let cancel = AtomicBool::new(false);
+        let solution = Grid::empty().solve_cancellable(&cancel).unwrap().unwrap();
+        assert_eq!(solution.grid, Grid::empty().solve().unwrap().grid);
+    }
+
+    #[test]
+    // Every solution of the 4×4 empty grid is found too quickly for the search to be cancelled
+    #[cfg(not(feature = "size-4x4"))]
+    fn cancel_from_another_thread() {
+        let cancel = AtomicBool::new(false);
+        let grid = Grid::empty();
+        std::thread::scope(|scope| {
+            // Enumerating every solution of the empty grid would take far too long
+            let search = scope.spawn(|| {
+                let mut solver = grid.try_solve();
+                while solver.next_cancellable(&cancel)?.is_some() {}
+                Ok(())
+            });
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            cancel.store(true, core::sync::atomic::Ordering::Relaxed);
+            assert_eq!(search.join().unwrap(), Err(Cancelled));
+        });
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{peers, Digit, Grid, Unit, NB_CELL, NB_DIGIT};
+
+/// A grid along with the digits each of its empty cells can still hold, see [Grid::candidates]
+/// Techniques like [CandidateGrid::apply_naked_pairs] rule out candidates without filling any cell,
+/// so the candidates are kept from one technique to the next instead of being computed again from the grid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CandidateGrid {
+    grid: Grid,
+    /// The candidates of each cell, as bit masks where the digit `d` is the bit `d - 1`
+    /// Filled cells have no candidate
+    pub(crate) masks: [u16; NB_CELL],
+}
+
+/// The bit of [d] in a candidate mask
+fn bit(d: Digit) -> u16 {
+    1 << (d.value() - 1)
+}
+
+impl Grid {
+    /// The candidates of every empty cell: the digits which are in none of its peers
+    pub fn candidates(&self) -> CandidateGrid {
+        let masks = core::array::from_fn(|pos| match self.data[pos] {
+            Some(_) => 0,
+            None => Digit::all()
+                .filter(|d| self.can_accept_digit_at_pos(*d, pos))
+                .fold(0, |mask, d| mask | bit(d)),
+        });
+        CandidateGrid {
+            grid: self.clone(),
+            masks,
+        }
+    }
+}
+
+/// The empty grid, where every digit can go everywhere
+impl Default for CandidateGrid {
+    fn default() -> Self {
+        Grid::empty().candidates()
+    }
+}
+
+impl CandidateGrid {
+    /// The grid, with the cells filled so far
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    /// Whether [d] can still go in the cell at [pos]
+    /// A filled cell has no candidate
+    pub fn is_candidate(&self, d: Digit, pos: usize) -> bool {
+        self.masks[pos] & bit(d) != 0
+    }
+
+    /// The digits which can still go in the cell at [pos], in increasing order
+    pub fn candidates(&self, pos: usize) -> impl Iterator<Item = Digit> + '_ {
+        Digit::all().filter(move |d| self.is_candidate(*d, pos))
+    }
+
+    /// Fill the cell at [pos] with [d], which is then no longer a candidate of its peers
+    /// PANIC if [d] is not a candidate of the cell
+    pub fn place(&mut self, d: Digit, pos: usize) {
+        assert!(self.is_candidate(d, pos), "{:?} can not go at {}", d, pos);
+        self.grid.data[pos] = Some(d);
+        self.masks[pos] = 0;
+        for &peer in peers(pos) {
+            self.masks[peer] &= !bit(d);
+        }
+    }
+
+    /// Fill every cell which has a single candidate, and every cell which is the only one of a row, a column
+    /// or a block where a digit can go, until there is no more such cell
+    /// Return how many cells have been filled
+    pub fn apply_singles(&mut self) -> usize {
+        let mut filled = 0;
+        loop {
+            let filled_before = filled;
+            for pos in 0..NB_CELL {
+                if self.masks[pos].count_ones() == 1 {
+                    let d = Digit(self.masks[pos].trailing_zeros() as u8 + 1);
+                    self.place(d, pos);
+                    filled += 1;
+                }
+            }
+            for unit in CandidateGrid::units() {
+                for d in Digit::all() {
+                    let mut positions = unit
+                        .cells()
+                        .into_iter()
+                        .filter(|pos| self.is_candidate(d, *pos));
+                    if let (Some(pos), None) = (positions.next(), positions.next()) {
+                        self.place(d, pos);
+                        filled += 1;
+                    }
+                }
+            }
+            if filled == filled_before {
+                return filled;
+            }
+        }
+    }
+
+    /// A cell with a single candidate, or else a digit which is a candidate of a single cell of a unit,
+    /// as the position of the cell and the digit which must go there
+    pub(crate) fn find_single(&self) -> Option<(usize, Digit)> {
+        let naked = (0..NB_CELL).find_map(|pos| {
+            (self.masks[pos].count_ones() == 1)
+                .then(|| (pos, Digit(self.masks[pos].trailing_zeros() as u8 + 1)))
+        });
+        naked.or_else(|| {
+            CandidateGrid::units().find_map(|unit| {
+                Digit::all().find_map(|d| {
+                    let mut positions = unit
+                        .cells()
+                        .into_iter()
+                        .filter(|pos| self.is_candidate(d, *pos));
+                    match (positions.next(), positions.next()) {
+                        (Some(pos), None) => Some((pos, d)),
+                        _ => None,
+                    }
+                })
+            })
+        })
+    }
+
+    /// Rule out [d] from the candidates of the cell at [pos], and return whether it was one
+    pub(crate) fn eliminate(&mut self, d: Digit, pos: usize) -> bool {
+        let was_candidate = self.is_candidate(d, pos);
+        self.masks[pos] &= !bit(d);
+        was_candidate
+    }
+
+    /// Every row, column and block
+    pub(crate) fn units() -> impl Iterator<Item = Unit> {
+        [Unit::Row, Unit::Column, Unit::Block]
+            .into_iter()
+            .flat_map(|unit| (0..NB_DIGIT).map(unit))
+    }
+}
+
+// The fixtures are 4×4 grids
+#[cfg(all(test, feature = "size-4x4"))]
+mod test {
+    use crate::{Digit, Grid};
+
+    #[test]
+    fn candidates_of_a_grid() {
+        let grid = Grid::from_hex_str("1..4.........2..").unwrap();
+        let candidates = grid.candidates();
+        let d = |v| Digit::new(v).unwrap();
+
+        assert_eq!(candidates.candidates(0).count(), 0);
+        assert_eq!(candidates.candidates(1).collect::<Vec<_>>(), [d(3)]);
+        assert_eq!(candidates.candidates(2).collect::<Vec<_>>(), [d(2), d(3)]);
+        assert!(!candidates.is_candidate(d(2), 5));
+        assert_eq!(candidates.grid(), &grid);
+    }
+
+    #[test]
+    fn singles_solve_the_grid() {
+        let grid = Grid::from_hex_str("1.3..4.22...4..1").unwrap();
+        assert!(grid.has_unique_solution());
+        let mut candidates = grid.candidates();
+
+        assert_eq!(candidates.apply_singles(), 9);
+        assert_eq!(candidates.grid(), grid.solve().unwrap().grid());
+        assert_eq!(candidates.candidates(5).count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn place_a_digit_which_is_not_a_candidate() {
+        let mut candidates = Grid::from_hex_str("1..4.........2..").unwrap().candidates();
+        candidates.place(Digit::new(4).unwrap(), 1);
+    }
+}
@@ -0,0 +1,127 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use itertools::Itertools;
+
+use crate::{Digit, Grid, BLOCK_SIDE, NB_CELL, NB_DIGIT};
+
+/// Every order of the rows (or columns) which keeps each band (or stack) together:
+/// the bands are permuted, and so are the rows within each band
+/// Item `i` of an order is the line of the original grid which goes at line `i`
+fn line_orders() -> Vec<[usize; NB_DIGIT]> {
+    let perms = (0..BLOCK_SIDE).permutations(BLOCK_SIDE).collect_vec();
+    let mut orders = Vec::new();
+    for bands in &perms {
+        for lines in core::iter::repeat_n(&perms, BLOCK_SIDE).multi_cartesian_product() {
+            orders.push(core::array::from_fn(|i| {
+                let band = bands[i / BLOCK_SIDE];
+                band * BLOCK_SIDE + lines[band][i % BLOCK_SIDE]
+            }));
+        }
+    }
+    orders
+}
+
+impl Grid {
+    /// The representative of every grid which is the same as this one up to the symmetries of the sudoku:
+    /// transposition, permutation of the bands, the stacks, the rows within a band, the columns within a stack,
+    /// and relabeling of the digits
+    /// `a.canonical() == b.canonical()` if and only if [a] can be turned into [b] by such symmetries
+    ///
+    /// Every arrangement is tried, and the one which reads the smallest in row-major order wins,
+    /// with the digits relabeled in their order of appearance and the empty cells first
+    /// There are `2 × 6^8` arrangements on 9×9 grids, so this is slow
+    /// On 16×16 grids there would be `2 × 24^10`, so this is not available with the `size-16x16` feature
+    pub fn canonical(&self) -> Grid {
+        let orders = line_orders();
+        let mut best = [u8::MAX; NB_CELL];
+        let mut labels_of_arrangement = [0; NB_CELL];
+        for grid in [self.clone(), self.transpose()] {
+            for rows in &orders {
+                for columns in &orders {
+                    // The labels given so far to each digit, 0 for no label yet
+                    let mut labels = [0; NB_DIGIT + 1];
+                    let mut nb_label = 0;
+                    let mut ordering = Ordering::Equal;
+                    for pos in 0..NB_CELL {
+                        let label = match grid[(rows[pos / NB_DIGIT], columns[pos % NB_DIGIT])] {
+                            None => 0,
+                            Some(d) => {
+                                let label = &mut labels[usize::from(d.value())];
+                                if *label == 0 {
+                                    nb_label += 1;
+                                    *label = nb_label;
+                                }
+                                *label
+                            }
+                        };
+                        labels_of_arrangement[pos] = label;
+                        if ordering == Ordering::Equal {
+                            ordering = label.cmp(&best[pos]);
+                            if ordering == Ordering::Greater {
+                                break;
+                            }
+                        }
+                    }
+                    if ordering == Ordering::Less {
+                        best = labels_of_arrangement;
+                    }
+                }
+            }
+        }
+        Grid {
+            data: best.map(|label| (label != 0).then_some(Digit(label))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    #[cfg(feature = "size-4x4")]
+    fn equivalent_grids() {
+        use std::collections::HashSet;
+
+        use crate::{Digit, Grid};
+
+        let d = |v| Digit::new(v).unwrap();
+        let grid = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        let same = grid
+            .rotate90()
+            .relabel(&[d(3), d(1), d(4), d(2)])
+            .unwrap()
+            .permute_bands(&[1, 0])
+            .unwrap();
+        assert_ne!(same, grid);
+        assert_eq!(same.canonical(), grid.canonical());
+        assert_eq!(grid.canonical().canonical(), grid.canonical());
+
+        let other = Grid::from_hex_str("12.4..12.1430...").unwrap();
+        assert_ne!(other.canonical(), grid.canonical());
+
+        // The 288 solutions of the 4×4 grid are of only 2 essentially different kinds
+        let kinds: HashSet<Grid> = Grid::empty()
+            .try_solve()
+            .map(|solution| solution.grid.canonical())
+            .collect();
+        assert_eq!(kinds.len(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "size-9x9")]
+    fn equivalent_puzzles() {
+        use crate::Grid;
+
+        let puzzle =
+            "8..........36......7..9.2...5...7.......457.....1...3...1....68..85...1..9....4.."
+                .parse::<Grid>()
+                .unwrap();
+        let same = puzzle
+            .transpose()
+            .permute_stacks(&[2, 0, 1])
+            .and_then(|g| g.permute_rows_in_band(1, &[1, 2, 0]))
+            .unwrap();
+        assert_eq!(same.canonical(), puzzle.canonical());
+        assert_eq!(Grid::empty().canonical(), Grid::empty());
+    }
+}
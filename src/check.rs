@@ -0,0 +1,107 @@
+use alloc::vec::Vec;
+
+use crate::{Cell, Grid, NB_CELL};
+
+/// Something wrong in a claimed solution, at the given position, see [Grid::check_solution]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolutionMistake {
+    /// The cell has been left empty
+    Empty(usize),
+    /// The digit differs from the one given by the puzzle
+    ChangedGiven(usize),
+    /// The digit is also in a cell of the same row, column or block
+    Conflict(usize),
+}
+
+impl Grid {
+    /// Check that [solution] is complete, valid, and keeps the givens of this puzzle
+    /// Return every mistake found, in position order, so an empty list means the solution is correct
+    pub fn check_solution(&self, solution: &[Cell; NB_CELL]) -> Vec<SolutionMistake> {
+        // Only used to look at the peers of each cell, which do not include the cell itself
+        let claimed = Grid { data: *solution };
+        (0..NB_CELL)
+            .filter_map(|pos| match (self.data[pos], solution[pos]) {
+                (_, None) => Some(SolutionMistake::Empty(pos)),
+                (Some(given), Some(d)) if given != d => Some(SolutionMistake::ChangedGiven(pos)),
+                (_, Some(d)) => (!claimed.can_accept_digit_at_pos(d, pos))
+                    .then_some(SolutionMistake::Conflict(pos)),
+            })
+            .collect()
+    }
+
+    /// Every cell which differs between the two grids, as `(position, cell in self, cell in other)`
+    /// in position order, so an empty list means the grids are the same
+    pub fn diff(&self, other: &Grid) -> Vec<(usize, Cell, Cell)> {
+        (0..NB_CELL)
+            .filter(|&pos| self.data[pos] != other.data[pos])
+            .map(|pos| (pos, self.data[pos], other.data[pos]))
+            .collect()
+    }
+}
+
+// The fixtures are 4×4 grids
+#[cfg(all(test, feature = "size-4x4"))]
+mod test {
+    use crate::{parse_cells, Grid, SolutionMistake};
+
+    #[test]
+    fn check_correct_solution() {
+        let puzzle = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        let solution = parse_cells("1234341221434321").unwrap();
+        assert_eq!(puzzle.check_solution(&solution), vec![]);
+    }
+
+    #[test]
+    fn check_wrong_solution() {
+        let puzzle = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        // Cell 2 is empty, and the swapped cells 12 and 13 clash with cells 4 and 5
+        let solution = parse_cells("12.4341221433421").unwrap();
+        assert_eq!(
+            puzzle.check_solution(&solution),
+            vec![
+                SolutionMistake::Empty(2),
+                SolutionMistake::Conflict(4),
+                SolutionMistake::Conflict(5),
+                SolutionMistake::Conflict(12),
+                SolutionMistake::Conflict(13),
+            ]
+        );
+        // Cells 2 and 3 are swapped, which changes the given of cell 3, and clashes with cells 10 and 11
+        let solution = parse_cells("1243341221434321").unwrap();
+        assert_eq!(
+            puzzle.check_solution(&solution),
+            vec![
+                SolutionMistake::Conflict(2),
+                SolutionMistake::ChangedGiven(3),
+                SolutionMistake::Conflict(10),
+                SolutionMistake::Conflict(11),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff() {
+        let puzzle = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        assert_eq!(puzzle.diff(&puzzle), vec![]);
+
+        let solution = puzzle.solve().unwrap().grid;
+        let mut attempt = puzzle.clone();
+        attempt[(0, 2)] = solution[(0, 2)];
+        attempt[(1, 0)] = solution[(1, 1)];
+        assert_eq!(
+            attempt.diff(&solution),
+            vec![
+                (4, solution[(1, 1)], solution[(1, 0)]),
+                (5, None, solution[(1, 1)]),
+                (8, None, solution[(2, 0)]),
+                (12, None, solution[(3, 0)]),
+                (13, None, solution[(3, 1)]),
+                (14, None, solution[(3, 2)]),
+            ]
+        );
+        assert_eq!(
+            solution.diff(&attempt)[0],
+            (4, solution[(1, 0)], solution[(1, 1)])
+        );
+    }
+}
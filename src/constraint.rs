@@ -0,0 +1,115 @@
+use alloc::boxed::Box;
+
+use crate::{Digit, Grid, GridSolver, NB_CELL};
+
+/// A rule which every digit of a solution must follow, see [Grid::try_solve_with_constraints]
+/// The rules of sudoku variants, like [crate::DiagonalConstraint] or [crate::CageConstraint], are constraints,
+/// so that they can be combined freely
+pub trait Constraint {
+    /// Whether [d] can go in the empty cell at [pos], given the digits already in [grid]
+    /// Only digits which break the rule may be rejected, otherwise solutions are lost
+    fn allows(&self, grid: &Grid, pos: usize, d: Digit) -> bool;
+}
+
+impl<C: Constraint + ?Sized> Constraint for &C {
+    fn allows(&self, grid: &Grid, pos: usize, d: Digit) -> bool {
+        (**self).allows(grid, pos, d)
+    }
+}
+
+/// The rules of the sudoku: each row, column and block holds every digit once
+/// The search always follows them, with bit masks which are much faster than this constraint,
+/// so it is only useful to check a grid, or to spell out the rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StandardConstraint;
+
+impl Constraint for StandardConstraint {
+    fn allows(&self, grid: &Grid, pos: usize, d: Digit) -> bool {
+        grid.can_accept_digit_at_pos(d, pos)
+    }
+}
+
+impl Grid {
+    /// Same as [Grid::try_solve], but every digit placed must also be allowed by each of [constraints]
+    /// The rules of the sudoku always apply: `&[&StandardConstraint]` gives the solutions of [Grid::try_solve]
+    /// Givens which already break a constraint leave no solution
+    pub fn try_solve_with_constraints<'a>(
+        &'a self,
+        constraints: &[&'a dyn Constraint],
+    ) -> GridSolver<'a> {
+        constraints
+            .iter()
+            .fold(GridSolver::from_grid(self), |solver, constraint| {
+                solver.with_constraint(Box::new(*constraint))
+            })
+    }
+
+    /// Whether [constraint] allows every given, as if it had been placed last
+    pub(crate) fn givens_follow(&self, constraint: &dyn Constraint) -> bool {
+        let mut grid = self.clone();
+        (0..NB_CELL).all(|pos| {
+            let Some(d) = grid.data[pos].take() else {
+                return true;
+            };
+            let allowed = constraint.allows(&grid, pos, d);
+            grid.data[pos] = Some(d);
+            allowed
+        })
+    }
+}
+
+// The fixtures are 4×4 grids
+#[cfg(all(test, feature = "size-4x4"))]
+mod test {
+    use crate::{Constraint, DiagonalConstraint, Digit, Grid, StandardConstraint};
+
+    #[test]
+    fn standard_constraint() {
+        let puzzle = Grid::from_hex_str("....43.1.4......").unwrap();
+        assert_eq!(
+            puzzle
+                .try_solve_with_constraints(&[&StandardConstraint])
+                .collect::<Vec<_>>(),
+            puzzle.try_solve().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            puzzle.try_solve_with_constraints(&[]).count(),
+            puzzle.count_solutions(None)
+        );
+    }
+
+    #[test]
+    fn standard_and_diagonal_constraints() {
+        let puzzle = Grid::from_hex_str("....43.1.4......").unwrap();
+        let solutions = puzzle
+            .try_solve_with_constraints(&[&StandardConstraint, &DiagonalConstraint])
+            .collect::<Vec<_>>();
+        assert_eq!(solutions.len(), 1);
+        assert_eq!(solutions[0].grid().to_line_string(), "2143432134121234");
+    }
+
+    /// Only odd digits in the first row
+    struct OddFirstRow;
+
+    impl Constraint for OddFirstRow {
+        fn allows(&self, _: &Grid, pos: usize, d: Digit) -> bool {
+            pos >= 4 || d.value() % 2 == 1
+        }
+    }
+
+    #[test]
+    fn custom_constraint() {
+        // Two odd digits can not fill a row of four
+        assert_eq!(
+            Grid::empty()
+                .try_solve_with_constraints(&[&OddFirstRow])
+                .next(),
+            None
+        );
+        let puzzle = Grid::from_hex_str("2...............").unwrap();
+        let mut solver = puzzle.try_solve_with_constraints(&[&OddFirstRow]);
+        assert_eq!(solver.next(), None);
+        solver.reset();
+        assert_eq!(solver.next(), None);
+    }
+}
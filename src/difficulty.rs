@@ -0,0 +1,108 @@
+use crate::Grid;
+
+/// How hard a puzzle is for a human, see [Grid::difficulty]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Difficulty {
+    /// Naked singles are enough: every cell can be filled as soon as only one digit fits in it
+    Easy,
+    /// Hidden singles are needed: some digit only fits in one cell of a row, a column or a block
+    Medium,
+    /// Candidates must be ruled out before singles show up, with naked pairs, pointing or X-Wings
+    Hard,
+    /// The techniques above get stuck, and guessing is needed
+    /// Puzzles without a solution, or with several, are also rated [Difficulty::Expert]
+    Expert,
+}
+
+impl Grid {
+    /// Rate the puzzle by solving it with logical techniques, from the simplest to the hardest,
+    /// always going back to the simplest one after some progress
+    /// The rating is the level of the hardest technique needed:
+    ///  - [Difficulty::Easy] for [Grid::apply_naked_singles]
+    ///  - [Difficulty::Medium] for [Grid::apply_hidden_singles]
+    ///  - [Difficulty::Hard] for [crate::CandidateGrid::apply_naked_pairs], [crate::CandidateGrid::apply_pointing]
+    ///    and [crate::CandidateGrid::apply_x_wing]
+    ///  - [Difficulty::Expert] when they all get stuck
+    ///
+    /// The grid itself is left untouched
+    pub fn difficulty(&self) -> Difficulty {
+        let mut grid = self.clone();
+        let mut difficulty = Difficulty::Easy;
+        loop {
+            if grid.is_solved() {
+                return difficulty;
+            }
+            if grid.apply_naked_singles() > 0 {
+                continue;
+            }
+            if grid.apply_hidden_singles() > 0 {
+                difficulty = difficulty.max(Difficulty::Medium);
+                continue;
+            }
+            break;
+        }
+
+        // Singles are stuck: only the techniques which rule out candidates can help
+        let mut candidates = grid.candidates();
+        loop {
+            let eliminated = candidates.apply_naked_pairs()
+                + candidates.apply_pointing()
+                + candidates.apply_x_wing();
+            if eliminated == 0 {
+                return Difficulty::Expert;
+            }
+            candidates.apply_singles();
+            if candidates.grid().is_solved() {
+                return Difficulty::Hard;
+            }
+        }
+    }
+
+    /// Whether the logical techniques of [Grid::difficulty] solve the puzzle, without any guess
+    /// The grid itself is left untouched
+    pub fn is_logically_solvable(&self) -> bool {
+        self.difficulty() < Difficulty::Expert
+    }
+}
+
+// The fixtures are 9×9 grids
+#[cfg(all(test, feature = "size-9x9"))]
+mod test {
+    use crate::{Difficulty, Grid};
+
+    const EASY: &str =
+        "53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79";
+    const MEDIUM: &str =
+        ".2.3..9....5..64.2.6..........6.......24.5...5.7..1.8..81.2...........34....13...";
+    const HARD: &str =
+        "...3..95....49.........8....6791..3..54......1....24..5..8.3.9..4........39.2...7";
+    const EXPERT: &str =
+        "8..........36......7..9.2...5...7.......457.....1...3...1....68..85...1..9....4..";
+
+    #[test]
+    fn rate_puzzles() {
+        for (puzzle, expected) in [
+            (EASY, Difficulty::Easy),
+            (MEDIUM, Difficulty::Medium),
+            (HARD, Difficulty::Hard),
+            (EXPERT, Difficulty::Expert),
+        ] {
+            let grid = puzzle.parse::<Grid>().unwrap();
+            assert_eq!(grid.difficulty(), expected);
+            assert_eq!(grid, puzzle.parse::<Grid>().unwrap());
+        }
+        assert_eq!(Grid::empty().difficulty(), Difficulty::Expert);
+    }
+
+    #[test]
+    fn logically_solvable() {
+        for puzzle in [EASY, MEDIUM, HARD] {
+            let grid = puzzle.parse::<Grid>().unwrap();
+            assert!(grid.is_logically_solvable());
+            assert_eq!(grid, puzzle.parse::<Grid>().unwrap());
+        }
+        assert!(!EXPERT.parse::<Grid>().unwrap().is_logically_solvable());
+        // Several solutions: one of them must be guessed
+        assert!(!Grid::empty().is_logically_solvable());
+    }
+}
@@ -0,0 +1,268 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::fmt;
+
+use crate::{Digit, DisplayStyle, Grid, BLOCK_SIDE, NB_CELL, NB_DIGIT};
+
+/// Display a [Grid] with column letters above it and row numbers on its left, see [Grid::display_with_coords]
+pub struct CoordDisplay<'a> {
+    grid: &'a Grid,
+}
+
+/// Display a [Grid] with its conflicting digits highlighted, see [Grid::display_conflicts]
+pub struct ConflictDisplay<'a> {
+    grid: &'a Grid,
+    color: bool,
+}
+
+impl Grid {
+    /// Display the grid like its [fmt::Display] implementation, but with the digits that are also in their row,
+    /// column or block highlighted
+    /// With `color` and the `color` feature, they are printed in red with ANSI escape codes.
+    /// Otherwise, they are surrounded by `*`, which makes every cell 3 characters wide
+    pub fn display_conflicts(&self, color: bool) -> ConflictDisplay<'_> {
+        ConflictDisplay { grid: self, color }
+    }
+
+    /// Draw the grid with, in each empty cell, its candidates laid out like the cells of a block:
+    /// for 9×9 grids, `1` is in the top left corner and `9` in the bottom right one.
+    /// Digits which can not be placed in the cell are shown as `.`,
+    /// and cells with no candidate at all are filled with `X`.
+    /// Filled cells only show their digit, in the middle of the cell
+    pub fn to_pencilmark_string(&self) -> String {
+        // Each cell is a square of BLOCK_SIDE characters, followed by a space
+        let block_width = 1 + BLOCK_SIDE * (BLOCK_SIDE + 1);
+        let border_line = |left: char, middle: char, right: char| {
+            let mut line = left.to_string();
+            for block in 0..BLOCK_SIDE {
+                if block > 0 {
+                    line.push(middle);
+                }
+                line.push_str(&"─".repeat(block_width));
+            }
+            line.push(right);
+            line.push('\n');
+            line
+        };
+
+        let mut s = border_line('┌', '┬', '┐');
+        for row in 0..NB_DIGIT {
+            if row > 0 && row % BLOCK_SIDE == 0 {
+                s.push_str(&border_line('├', '┼', '┤'));
+            } else if row > 0 {
+                // Blank line between the cells of a block
+                for _ in 0..BLOCK_SIDE {
+                    s.push('│');
+                    s.push_str(&" ".repeat(block_width));
+                }
+                s.push_str("│\n");
+            }
+            for mark_row in 0..BLOCK_SIDE {
+                for column in 0..NB_DIGIT {
+                    if column % BLOCK_SIDE == 0 {
+                        s.push_str("│ ");
+                    }
+                    let pos = row * NB_DIGIT + column;
+                    for mark_column in 0..BLOCK_SIDE {
+                        s.push(self.pencilmark_char(pos, mark_row, mark_column));
+                    }
+                    s.push(' ');
+                }
+                s.push_str("│\n");
+            }
+        }
+        s.push_str(&border_line('└', '┴', '┘'));
+        s
+    }
+
+    /// The character at ([mark_row], [mark_column]) in the pencil-marks of the cell at [pos]
+    fn pencilmark_char(&self, pos: usize, mark_row: usize, mark_column: usize) -> char {
+        let center = BLOCK_SIDE / 2;
+        match self.data[pos] {
+            Some(digit) if (mark_row, mark_column) == (center, center) => digit.to_char(),
+            Some(_) => ' ',
+            None if Digit::all().all(|d| !self.can_accept_digit_at_pos(d, pos)) => 'X',
+            None => {
+                let digit = Digit((mark_row * BLOCK_SIDE + mark_column + 1) as u8);
+                if self.can_accept_digit_at_pos(digit, pos) {
+                    digit.to_char()
+                } else {
+                    '.'
+                }
+            }
+        }
+    }
+
+    /// Display the grid like its [fmt::Display] implementation, with columns labelled `A`, `B`, ...
+    /// and rows labelled `1`, `2`, ...
+    pub fn display_with_coords(&self) -> CoordDisplay<'_> {
+        CoordDisplay { grid: self }
+    }
+}
+
+impl fmt::Display for CoordDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Row labels are right-aligned, so that two digit labels keep the grid aligned
+        let label_width = NB_DIGIT.to_string().len();
+
+        // One letter above each cell, and a space above each border
+        let mut header = " ".repeat(label_width + 1);
+        for column in 0..NB_DIGIT {
+            if column > 0 && column % BLOCK_SIDE == 0 {
+                header.push(' ');
+            }
+            header.push(char::from(b'A' + column as u8));
+        }
+        writeln!(f, "{}", header)?;
+
+        let mut row = 0;
+        for line in self.grid.to_string().lines() {
+            // Lines of cells start with a vertical border, the others are horizontal borders
+            if line.starts_with('│') {
+                row += 1;
+                writeln!(f, "{:>width$}{}", row, line, width = label_width)?;
+            } else {
+                writeln!(f, "{:width$}{}", "", line, width = label_width)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ConflictDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let grid = self.grid;
+        let conflicts: [bool; NB_CELL] = core::array::from_fn(|pos| {
+            grid.data[pos].is_some_and(|d| !grid.can_accept_digit_at_pos(d, pos))
+        });
+        let cell_char = |pos: usize| grid.data[pos].map_or('.', Digit::to_char);
+
+        if self.color && cfg!(feature = "color") {
+            grid.write_boxed_cells(f, DisplayStyle::Unicode, 1, |pos| {
+                if conflicts[pos] {
+                    format!("\x1b[31m{}\x1b[0m", cell_char(pos))
+                } else {
+                    cell_char(pos).to_string()
+                }
+            })
+        } else {
+            grid.write_boxed_cells(f, DisplayStyle::Unicode, 3, |pos| {
+                let marker = if conflicts[pos] { '*' } else { ' ' };
+                format!("{}{}{}", marker, cell_char(pos), marker)
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Grid;
+
+    #[test]
+    #[cfg(feature = "size-4x4")]
+    fn display_with_coords() {
+        let grid = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        assert_eq!(
+            grid.display_with_coords().to_string(),
+            r"  AB CD
+ ┌──┬──┐
+1│12│.4│
+2│..│12│
+ ├──┼──┤
+3│.1│43│
+4│..│.1│
+ └──┴──┘
+"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "size-4x4")]
+    fn pencilmarks() {
+        let grid = Grid::from_hex_str("1..4.........2..").unwrap();
+        assert_eq!(
+            grid.to_pencilmark_string(),
+            r"┌───────┬───────┐
+│    .. │ .2    │
+│  1 3. │ 3.  4 │
+│       │       │
+│ .2 .. │ 12 12 │
+│ 34 34 │ 3. 3. │
+├───────┼───────┤
+│ .. 1. │ 12 12 │
+│ 34 34 │ 34 3. │
+│       │       │
+│ ..    │ 1. 1. │
+│ 34  2 │ 34 3. │
+└───────┴───────┘
+"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "size-4x4")]
+    fn pencilmarks_of_a_cell_without_candidates() {
+        let grid = Grid::from_hex_str("12.....3......4.").unwrap();
+        assert!(grid.to_pencilmark_string().starts_with(
+            r"┌───────┬───────┐
+│       │ XX .. │
+│  1  2 │ XX .4 │
+"
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "size-4x4")]
+    fn display_conflicts_with_markers() {
+        let mut grid = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        // Same digit as cell 0 in its column, and as cell 9 in its row
+        grid.data[8] = grid.data[0];
+        assert_eq!(
+            grid.display_conflicts(false).to_string(),
+            r"┌──────┬──────┐
+│*1* 2 │ .  4 │
+│ .  . │ 1  2 │
+├──────┼──────┤
+│*1**1*│ 4  3 │
+│ .  . │ .  1 │
+└──────┴──────┘
+"
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "size-4x4", feature = "color"))]
+    fn display_conflicts_in_color() {
+        let mut grid = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        grid.data[8] = grid.data[0];
+        let s = grid.display_conflicts(true).to_string();
+        assert!(s.starts_with("┌──┬──┐\n│\x1b[31m1\x1b[0m2│.4│\n"));
+        // Without conflicts, it is the same as the plain display
+        grid.data[8] = None;
+        assert_eq!(grid.display_conflicts(true).to_string(), grid.to_string());
+    }
+
+    #[test]
+    #[cfg(feature = "size-9x9")]
+    fn display_with_coords_9x9() {
+        let s = Grid::empty().display_with_coords().to_string();
+        let lines = s.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "  ABC DEF GHI");
+        assert_eq!(lines[1], " ┌───┬───┬───┐");
+        assert_eq!(lines[2], "1│...│...│...│");
+        assert_eq!(lines[lines.len() - 2], "9│...│...│...│");
+    }
+
+    #[test]
+    #[cfg(feature = "size-16x16")]
+    fn display_with_coords_two_digit_rows() {
+        let s = Grid::empty().display_with_coords().to_string();
+        let lines = s.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "   ABCD EFGH IJKL MNOP");
+        assert_eq!(lines[1], "  ┌────┬────┬────┬────┐");
+        assert_eq!(lines[2], " 1│....│....│....│....│");
+        assert_eq!(lines[lines.len() - 2], "16│....│....│....│....│");
+    }
+}
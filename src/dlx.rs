@@ -0,0 +1,267 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{Digit, Grid, SolvedGrid, BLOCK_SIDE, NB_CELL, NB_DIGIT};
+
+/// Every constraint of the exact cover problem: each cell holds a digit, and each row, column and block
+/// holds each digit, exactly once
+const NB_CONSTRAINT: usize = 4 * NB_CELL;
+
+/// The sparse matrix of Knuth's Dancing Links, as circular doubly linked lists stored in vectors
+/// Node 0 is the root, nodes `1..=NB_CONSTRAINT` are the column headers, and every other node
+/// is the intersection of a candidate (a digit in a cell) with one of the 4 constraints it satisfies
+struct Dlx {
+    left: Vec<usize>,
+    right: Vec<usize>,
+    up: Vec<usize>,
+    down: Vec<usize>,
+    /// The column header of each node
+    header: Vec<usize>,
+    /// The candidate of each node, as `pos * NB_DIGIT + digit - 1`
+    candidate: Vec<usize>,
+    /// The number of nodes of each column, indexed by its header
+    size: Vec<usize>,
+    /// A node of each candidate chosen so far
+    chosen: Vec<usize>,
+}
+
+impl Dlx {
+    /// The matrix of [grid]: its givens are its only candidates in their cells, so they are chosen first
+    fn new(grid: &Grid) -> Dlx {
+        let mut dlx = Dlx {
+            left: Vec::new(),
+            right: Vec::new(),
+            up: Vec::new(),
+            down: Vec::new(),
+            header: Vec::new(),
+            candidate: Vec::new(),
+            size: vec![0; NB_CONSTRAINT + 1],
+            chosen: Vec::with_capacity(NB_CELL),
+        };
+        for node in 0..=NB_CONSTRAINT {
+            dlx.left
+                .push(if node == 0 { NB_CONSTRAINT } else { node - 1 });
+            dlx.right
+                .push(if node == NB_CONSTRAINT { 0 } else { node + 1 });
+            dlx.up.push(node);
+            dlx.down.push(node);
+            dlx.header.push(node);
+            dlx.candidate.push(usize::MAX);
+        }
+
+        for pos in 0..NB_CELL {
+            for d in Digit::all() {
+                if grid.data[pos].is_some_and(|given| given != d) {
+                    continue;
+                }
+                dlx.add_candidate(pos, d);
+            }
+        }
+        dlx
+    }
+
+    /// Add the row of the matrix which stands for [d] in the cell at [pos]
+    fn add_candidate(&mut self, pos: usize, d: Digit) {
+        let (row, column) = (pos / NB_DIGIT, pos % NB_DIGIT);
+        let block = row / BLOCK_SIDE * BLOCK_SIDE + column / BLOCK_SIDE;
+        let digit = usize::from(d.value() - 1);
+        let constraints = [
+            pos,
+            NB_CELL + row * NB_DIGIT + digit,
+            2 * NB_CELL + column * NB_DIGIT + digit,
+            3 * NB_CELL + block * NB_DIGIT + digit,
+        ];
+
+        let first = self.header.len();
+        for (i, constraint) in constraints.into_iter().enumerate() {
+            let header = constraint + 1;
+            let node = first + i;
+            self.left.push(if i == 0 { first + 3 } else { node - 1 });
+            self.right.push(if i == 3 { first } else { node + 1 });
+            // Insert at the bottom of the column
+            self.up.push(self.up[header]);
+            self.down.push(header);
+            let bottom = self.up[header];
+            self.down[bottom] = node;
+            self.up[header] = node;
+            self.header.push(header);
+            self.candidate.push(pos * NB_DIGIT + digit);
+            self.size[header] += 1;
+        }
+    }
+
+    /// Remove the column from the header list, and every row which has a node in it from the other columns
+    fn cover(&mut self, header: usize) {
+        self.right[self.left[header]] = self.right[header];
+        self.left[self.right[header]] = self.left[header];
+        let mut row = self.down[header];
+        while row != header {
+            let mut node = self.right[row];
+            while node != row {
+                self.down[self.up[node]] = self.down[node];
+                self.up[self.down[node]] = self.up[node];
+                self.size[self.header[node]] -= 1;
+                node = self.right[node];
+            }
+            row = self.down[row];
+        }
+    }
+
+    /// Undo [Dlx::cover], which must be the last cover not undone yet
+    fn uncover(&mut self, header: usize) {
+        let mut row = self.up[header];
+        while row != header {
+            let mut node = self.left[row];
+            while node != row {
+                self.size[self.header[node]] += 1;
+                self.down[self.up[node]] = node;
+                self.up[self.down[node]] = node;
+                node = self.left[node];
+            }
+            row = self.up[row];
+        }
+        self.right[self.left[header]] = header;
+        self.left[self.right[header]] = header;
+    }
+
+    /// Knuth's Algorithm X: call [on_solution] with the grid of every exact cover, until it returns false
+    /// Return false if the search has been stopped this way
+    fn search(&mut self, on_solution: &mut impl FnMut(Grid) -> bool) -> bool {
+        if self.right[0] == 0 {
+            let mut grid = Grid::empty();
+            for &node in &self.chosen {
+                let candidate = self.candidate[node];
+                grid.data[candidate / NB_DIGIT] = Some(Digit((candidate % NB_DIGIT) as u8 + 1));
+            }
+            return on_solution(grid);
+        }
+
+        // The constraint with the fewest candidates left
+        let mut header = self.right[0];
+        let mut best = header;
+        while header != 0 {
+            if self.size[header] < self.size[best] {
+                best = header;
+            }
+            header = self.right[header];
+        }
+        let header = best;
+
+        self.cover(header);
+        let mut row = self.down[header];
+        let mut go_on = true;
+        while go_on && row != header {
+            self.chosen.push(row);
+            let mut node = self.right[row];
+            while node != row {
+                self.cover(self.header[node]);
+                node = self.right[node];
+            }
+
+            go_on = self.search(on_solution);
+
+            let mut node = self.left[row];
+            while node != row {
+                self.uncover(self.header[node]);
+                node = self.left[node];
+            }
+            self.chosen.pop();
+            row = self.down[row];
+        }
+        self.uncover(header);
+        go_on
+    }
+}
+
+impl Grid {
+    /// Same as [Grid::solve], but with Knuth's Algorithm X on Dancing Links, which is much faster on hard puzzles
+    /// When the grid has several solutions, the one returned may differ
+    pub fn solve_dlx(&self) -> Option<SolvedGrid> {
+        let mut solution = None;
+        Dlx::new(self).search(&mut |grid| {
+            solution = Some(SolvedGrid { grid });
+            false
+        });
+        solution
+    }
+
+    /// Same as [Grid::count_solutions], but with Knuth's Algorithm X on Dancing Links, see [Grid::solve_dlx]
+    pub fn count_solutions_dlx(&self, limit: Option<usize>) -> usize {
+        let limit = limit.unwrap_or(usize::MAX);
+        let mut count = 0;
+        if limit > 0 {
+            Dlx::new(self).search(&mut |_| {
+                count += 1;
+                count < limit
+            });
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Grid;
+
+    #[test]
+    #[cfg(feature = "size-4x4")]
+    fn same_solution_counts_as_backtracking() {
+        use rand::{rngs::SmallRng, seq::SliceRandom, SeedableRng};
+
+        use crate::{Digit, NB_CELL};
+
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..200 {
+            // Random givens, kept when they do not contradict each other
+            let mut positions: Vec<usize> = (0..NB_CELL).collect();
+            positions.shuffle(&mut rng);
+            let mut grid = Grid::empty();
+            for &pos in &positions[..5] {
+                let mut digits: Vec<Digit> = Digit::all().collect();
+                digits.shuffle(&mut rng);
+                if let Some(d) = digits
+                    .into_iter()
+                    .find(|d| grid.can_accept_digit_at_pos(*d, pos))
+                {
+                    grid.data[pos] = Some(d);
+                }
+            }
+
+            assert_eq!(grid.count_solutions_dlx(None), grid.count_solutions(None));
+            assert_eq!(grid.solve_dlx().is_some(), grid.solve().is_some());
+        }
+        assert_eq!(Grid::empty().count_solutions_dlx(None), 288);
+        assert_eq!(Grid::empty().count_solutions_dlx(Some(10)), 10);
+    }
+
+    #[test]
+    #[cfg(feature = "size-9x9")]
+    fn solve_hard_puzzle() {
+        let puzzle =
+            "8..........36......7..9.2...5...7.......457.....1...3...1....68..85...1..9....4.."
+                .parse::<Grid>()
+                .unwrap();
+        let solution = puzzle.solve_dlx().unwrap();
+        assert!(solution.grid().is_solved());
+        assert_eq!(
+            solution.grid(),
+            puzzle.try_solve_mrv().next().unwrap().grid()
+        );
+        assert_eq!(puzzle.count_solutions_dlx(None), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "size-9x9")]
+    fn unsolvable_puzzle() {
+        // The first row misses a 9, which the last column already holds
+        let puzzle = format!("12345678.........9{}", ".".repeat(63))
+            .parse::<Grid>()
+            .unwrap();
+        assert!(puzzle.solve_dlx().is_none());
+        assert_eq!(puzzle.count_solutions_dlx(None), 0);
+    }
+
+    #[test]
+    fn empty_grid() {
+        assert!(Grid::empty().solve_dlx().unwrap().grid().is_solved());
+    }
+}
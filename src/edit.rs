@@ -0,0 +1,242 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::{cell_index, Cell, Digit, Grid, NB_CELL, NB_DIGIT};
+
+/// Raw mutable access to the cells of a [Grid], obtained with [Grid::edit]
+/// Cells can be changed freely while the editor is alive, the grid is only validated at the end of the edit:
+/// If a digit contradicts another one, the grid is restored to its state before the edit
+/// End the edit with [GridEditor::commit] to know whether it has been kept. When the editor is just dropped,
+/// the rollback is silent in release builds, and panics in debug builds so that the bug is noticed
+pub struct GridEditor<'a> {
+    grid: &'a mut Grid,
+    backup: [Cell; NB_CELL],
+}
+
+/// The coordinates are not in the grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfBounds {
+    pub row: usize,
+    pub column: usize,
+}
+
+/// Why [Grid::set] refused to change a cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetError {
+    OutOfBounds(OutOfBounds),
+    /// The digit is already in the row, the column or the block of the cell
+    Contradiction(Digit),
+}
+
+/// Why [GridEditor::commit] rolled the edit back: the digit at [pos] is also in its row, its column or its block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Contradiction {
+    pub pos: usize,
+    pub digit: Digit,
+}
+
+impl From<OutOfBounds> for SetError {
+    fn from(e: OutOfBounds) -> Self {
+        SetError::OutOfBounds(e)
+    }
+}
+
+impl Grid {
+    /// The cell at [row] and [column], or None if they are out of the grid
+    pub fn get(&self, row: usize, column: usize) -> Option<Cell> {
+        (row < NB_DIGIT && column < NB_DIGIT).then(|| self[(row, column)])
+    }
+
+    /// Put [value] in the cell at [row] and [column], and return its previous value, so that the move can be undone
+    /// The grid is left untouched if the digit contradicts another one
+    pub fn set(&mut self, row: usize, column: usize, value: Cell) -> Result<Cell, SetError> {
+        self.get(row, column).ok_or(OutOfBounds { row, column })?;
+        if let Some(d) = value {
+            if !self.can_accept_digit_at_pos(d, cell_index(row, column)) {
+                return Err(SetError::Contradiction(d));
+            }
+        }
+        Ok(core::mem::replace(&mut self[(row, column)], value))
+    }
+
+    /// Same as [Grid::set], but the digit may contradict another one: check with [Grid::is_valid]
+    pub fn set_unchecked(
+        &mut self,
+        row: usize,
+        column: usize,
+        value: Cell,
+    ) -> Result<Cell, OutOfBounds> {
+        self.get(row, column).ok_or(OutOfBounds { row, column })?;
+        Ok(core::mem::replace(&mut self[(row, column)], value))
+    }
+
+    /// Make many changes to the grid at once, and pay for the validation only once, see [GridEditor]
+    /// If the changes put digits in contradiction, they are all rolled back at the end of the edit:
+    /// [GridEditor::commit] reports it, otherwise it panics in debug builds and goes unnoticed in release builds
+    pub fn edit(&mut self) -> GridEditor<'_> {
+        GridEditor {
+            backup: self.data,
+            grid: self,
+        }
+    }
+}
+
+impl GridEditor<'_> {
+    /// Whether no digit contradicts another one so far, so that the edit would be kept if it ended now
+    pub fn is_valid(&self) -> bool {
+        self.grid.is_valid()
+    }
+
+    /// End the edit: keep the changes, or roll them all back and return the first digit in contradiction
+    /// Unlike dropping the editor, this never panics
+    pub fn commit(self) -> Result<(), Contradiction> {
+        let contradiction = (0..NB_CELL).find_map(|pos| {
+            self.grid.data[pos]
+                .filter(|d| !self.grid.can_accept_digit_at_pos(*d, pos))
+                .map(|digit| Contradiction { pos, digit })
+        });
+        if contradiction.is_some() {
+            self.grid.data = self.backup;
+        }
+        // The outcome is reported, there is nothing left for the drop to check
+        core::mem::forget(self);
+        contradiction.map_or(Ok(()), Err)
+    }
+}
+
+impl Deref for GridEditor<'_> {
+    type Target = [Cell; NB_CELL];
+
+    fn deref(&self) -> &Self::Target {
+        &self.grid.data
+    }
+}
+
+impl DerefMut for GridEditor<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.grid.data
+    }
+}
+
+impl Drop for GridEditor<'_> {
+    fn drop(&mut self) {
+        if self.grid.is_valid() {
+            return;
+        }
+        self.grid.data = self.backup;
+        // Do not panic while already unwinding, that would abort the process
+        // Without std, panics do not unwind
+        #[cfg(feature = "std")]
+        let panicking = std::thread::panicking();
+        #[cfg(not(feature = "std"))]
+        let panicking = false;
+        if cfg!(debug_assertions) && !panicking {
+            panic!("The edit put digits in contradiction, it has been rolled back");
+        }
+    }
+}
+
+// The fixtures are 4×4 grids
+#[cfg(all(test, feature = "size-4x4"))]
+mod test {
+    use crate::{Contradiction, Digit, Grid, OutOfBounds, SetError};
+
+    #[test]
+    fn edit_many_cells() {
+        let mut grid = Grid::empty();
+        {
+            let mut editor = grid.edit();
+            editor[0] = Some(Digit::new(1).unwrap());
+            editor[5] = Some(Digit::new(2).unwrap());
+            editor[10] = Some(Digit::new(3).unwrap());
+            editor[15] = Some(Digit::new(4).unwrap());
+        }
+
+        #[rustfmt::skip]
+        let expected = Grid::from_u8s([
+            1, 0, 0, 0,
+            0, 2, 0, 0,
+            0, 0, 3, 0,
+            0, 0, 0, 4,
+        ]);
+        assert_eq!(grid, expected);
+    }
+
+    #[test]
+    fn edit_with_contradiction_is_rolled_back() {
+        let mut grid = Grid::empty();
+        grid.edit()[0] = Some(Digit::new(1).unwrap());
+        let before = grid.clone();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut editor = grid.edit();
+            editor[1] = Some(Digit::new(1).unwrap());
+            editor[2] = Some(Digit::new(2).unwrap());
+        }));
+
+        assert_eq!(result.is_err(), cfg!(debug_assertions));
+        assert_eq!(grid, before);
+    }
+
+    #[test]
+    fn commit_edit() {
+        let one = Some(Digit::new(1).unwrap());
+        let mut grid = Grid::empty();
+
+        let mut editor = grid.edit();
+        editor[0] = one;
+        assert!(editor.is_valid());
+        assert_eq!(editor.commit(), Ok(()));
+        assert_eq!(grid[(0, 0)], one);
+        let before = grid.clone();
+
+        // Rolled back without panicking, even in debug builds
+        let mut editor = grid.edit();
+        editor[6] = Some(Digit::new(2).unwrap());
+        editor[5] = one;
+        assert!(!editor.is_valid());
+        assert_eq!(
+            editor.commit(),
+            Err(Contradiction {
+                pos: 0,
+                digit: Digit::new(1).unwrap()
+            })
+        );
+        assert_eq!(grid, before);
+    }
+
+    #[test]
+    fn get_and_set() {
+        let one = Some(Digit::new(1).unwrap());
+        let two = Some(Digit::new(2).unwrap());
+        let mut grid = Grid::empty();
+
+        assert_eq!(grid.set(0, 0, one), Ok(None));
+        assert_eq!(grid.get(0, 0), Some(one));
+        assert_eq!(grid.set(0, 0, two), Ok(one));
+        assert_eq!(grid.set(0, 0, None), Ok(two));
+        assert_eq!(grid.get(0, 0), Some(None));
+
+        grid.set(0, 0, one).unwrap();
+        assert_eq!(
+            grid.set(1, 1, one),
+            Err(SetError::Contradiction(Digit::new(1).unwrap()))
+        );
+        assert_eq!(grid.get(1, 1), Some(None));
+
+        assert_eq!(grid.set_unchecked(1, 1, one), Ok(None));
+        assert!(!grid.is_valid());
+    }
+
+    #[test]
+    fn get_and_set_out_of_the_grid() {
+        let mut grid = Grid::empty();
+        assert_eq!(grid.get(4, 0), None);
+        assert_eq!(grid.get(0, 4), None);
+        let out_of_bounds = OutOfBounds { row: 0, column: 4 };
+        assert_eq!(
+            grid.set(0, 4, None),
+            Err(SetError::OutOfBounds(out_of_bounds))
+        );
+        assert_eq!(grid.set_unchecked(0, 4, None), Err(out_of_bounds));
+    }
+}
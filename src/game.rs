@@ -0,0 +1,163 @@
+use alloc::vec::Vec;
+
+use crate::{Cell, Digit, Grid, OutOfBounds, SetError, NB_CELL, NB_DIGIT};
+
+/// A change of a cell, as played by [Game::play]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub pos: usize,
+    /// The value of the cell before the move, put back by [Game::undo]
+    pub previous: Cell,
+    pub value: Cell,
+}
+
+/// Why [Game::play] refused a move
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveError {
+    OutOfBounds(OutOfBounds),
+    /// The cell holds a clue of the puzzle, which can not be changed
+    Given(usize),
+    /// The digit is already in the row, the column or the block of the cell
+    Contradiction(Digit),
+}
+
+impl From<SetError> for MoveError {
+    fn from(e: SetError) -> Self {
+        match e {
+            SetError::OutOfBounds(e) => MoveError::OutOfBounds(e),
+            SetError::Contradiction(d) => MoveError::Contradiction(d),
+        }
+    }
+}
+
+/// A puzzle being played, with the history of the moves so that they can be undone and redone
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    grid: Grid,
+    givens: [bool; NB_CELL],
+    undo: Vec<Move>,
+    /// The moves undone since the last one played, the next to redo last
+    redo: Vec<Move>,
+}
+
+impl Game {
+    /// Start playing [puzzle], whose digits are the givens
+    pub fn new(puzzle: Grid) -> Game {
+        Game {
+            givens: puzzle.givens(),
+            grid: puzzle,
+            undo: Vec::new(),
+            redo: Vec::new(),
+        }
+    }
+
+    /// The puzzle with the moves played so far
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    /// The cells which can not be played, see [Grid::givens]
+    pub fn givens(&self) -> &[bool; NB_CELL] {
+        &self.givens
+    }
+
+    /// Put [value] in the cell at [pos], None to erase it, like [Grid::set]
+    /// The moves undone so far can no longer be redone
+    pub fn play(&mut self, pos: usize, value: Cell) -> Result<(), MoveError> {
+        if self.givens.get(pos) == Some(&true) {
+            return Err(MoveError::Given(pos));
+        }
+        let previous = self.grid.set(pos / NB_DIGIT, pos % NB_DIGIT, value)?;
+        self.undo.push(Move {
+            pos,
+            previous,
+            value,
+        });
+        self.redo.clear();
+        Ok(())
+    }
+
+    /// Take back the last move played or redone, and return it, or None if there is none
+    pub fn undo(&mut self) -> Option<Move> {
+        let last = self.undo.pop()?;
+        // The grid goes back to its state before the move, which was valid
+        self.grid.data[last.pos] = last.previous;
+        self.redo.push(last);
+        Some(last)
+    }
+
+    /// Play again the last move undone, and return it, or None if there is none
+    pub fn redo(&mut self) -> Option<Move> {
+        let next = self.redo.pop()?;
+        // The grid is back to its state after the undo, where the move was valid
+        self.grid.data[next.pos] = next.value;
+        self.undo.push(next);
+        Some(next)
+    }
+}
+
+// The fixtures are 4×4 grids
+#[cfg(all(test, feature = "size-4x4"))]
+mod test {
+    use crate::{Digit, Game, Grid, Move, MoveError, OutOfBounds};
+
+    #[test]
+    fn play_undo_redo() {
+        let d = |v| Some(Digit::new(v).unwrap());
+        let puzzle = Grid::from_hex_str("12.4..12.143...1").unwrap();
+        let mut game = Game::new(puzzle.clone());
+        assert_eq!(game.undo(), None);
+
+        game.play(2, d(3)).unwrap();
+        let after_first = game.grid().clone();
+        game.play(4, d(3)).unwrap();
+        game.play(4, None).unwrap();
+        let after_all = game.grid().clone();
+
+        assert_eq!(
+            game.undo(),
+            Some(Move {
+                pos: 4,
+                previous: d(3),
+                value: None
+            })
+        );
+        assert_eq!(game.grid().data[4], d(3));
+        game.undo();
+        assert_eq!(game.grid(), &after_first);
+        game.undo();
+        assert_eq!(game.grid(), &puzzle);
+        assert_eq!(game.undo(), None);
+
+        for _ in 0..3 {
+            game.redo().unwrap();
+        }
+        assert_eq!(game.grid(), &after_all);
+        assert_eq!(game.redo(), None);
+
+        // Playing forgets the moves undone
+        game.undo();
+        game.play(5, d(4)).unwrap();
+        assert_eq!(game.redo(), None);
+    }
+
+    #[test]
+    fn refused_moves() {
+        let d = |v| Some(Digit::new(v).unwrap());
+        let puzzle = Grid::from_hex_str("12.4..12.143...1").unwrap();
+        let mut game = Game::new(puzzle.clone());
+
+        assert_eq!(game.play(0, d(3)), Err(MoveError::Given(0)));
+        assert_eq!(game.play(1, None), Err(MoveError::Given(1)));
+        assert_eq!(
+            game.play(2, d(1)),
+            Err(MoveError::Contradiction(Digit::new(1).unwrap()))
+        );
+        assert_eq!(
+            game.play(16, d(1)),
+            Err(MoveError::OutOfBounds(OutOfBounds { row: 4, column: 0 }))
+        );
+        assert_eq!(game.grid(), &puzzle);
+        assert_eq!(game.undo(), None);
+    }
+}
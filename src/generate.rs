@@ -0,0 +1,266 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{Digit, Grid, SolvedGrid, Technique, NB_CELL, NB_DIGIT};
+
+/// Options of [Grid::generate_with_options]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenerateOptions {
+    /// The mix of techniques the puzzle should be solved with, or None to accept any puzzle
+    pub technique_profile: Option<TechniqueProfile>,
+    /// How many puzzles to generate at most while looking for one which matches [GenerateOptions::technique_profile]
+    pub max_attempts: usize,
+}
+
+/// Any puzzle, and up to 100 attempts once a profile is set
+impl Default for GenerateOptions {
+    fn default() -> Self {
+        GenerateOptions {
+            technique_profile: None,
+            max_attempts: 100,
+        }
+    }
+}
+
+/// The targeted mix of techniques in the deductions which solve a puzzle, as traced by [Grid::solve_logically]
+/// "Mostly naked singles, some hidden singles" would be `[(NakedSingle, 0.7), (HiddenSingle, 0.3)]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TechniqueProfile {
+    /// The targeted share of the deductions made with each technique, between 0 and 1
+    /// The techniques which are not listed should not be used at all
+    pub shares: Vec<(Technique, f32)>,
+    /// How far from its target the share of each technique may be
+    pub tolerance: f32,
+}
+
+impl TechniqueProfile {
+    /// Whether the puzzle is solved without guessing, with the targeted mix of techniques
+    pub fn matches(&self, puzzle: &Grid) -> bool {
+        self.distance(puzzle) <= self.tolerance
+    }
+
+    /// How far from its target the share of the technique which is the most off is
+    /// Infinite if the puzzle can not be solved without guessing
+    fn distance(&self, puzzle: &Grid) -> f32 {
+        let (grid, trace) = puzzle.solve_logically();
+        if !grid.is_solved() || trace.is_empty() {
+            return f32::INFINITY;
+        }
+        [
+            Technique::NakedSingle,
+            Technique::HiddenSingle,
+            Technique::NakedPair,
+            Technique::Pointing,
+            Technique::XWing,
+        ]
+        .into_iter()
+        .map(|technique| {
+            let used = trace.iter().filter(|step| step.technique() == technique);
+            let share = used.count() as f32 / trace.len() as f32;
+            let target = self
+                .shares
+                .iter()
+                .find(|(listed, _)| *listed == technique)
+                .map_or(0.0, |(_, target)| *target);
+            (share - target).abs()
+        })
+        .fold(0.0, f32::max)
+    }
+}
+
+impl Grid {
+    /// Generate a random puzzle with a unique solution, and return it with its solution
+    /// Starting from a random complete grid, the clues are removed one at a time in a random order,
+    /// unless the solution would no longer be unique: in the end, no clue can be removed without losing uniqueness
+    pub fn generate(rng: &mut impl Rng) -> (Grid, SolvedGrid) {
+        let solution = Grid::random_solution(rng);
+        let mut puzzle = solution.grid.clone();
+
+        let mut positions: [usize; NB_CELL] = core::array::from_fn(|pos| pos);
+        positions.shuffle(rng);
+        for pos in positions {
+            let clue = puzzle.data[pos].take();
+            // Proving that there is no second solution is much faster by guessing the most constrained cells first
+            if puzzle.try_solve_mrv().take(2).count() != 1 {
+                puzzle.data[pos] = clue;
+            }
+        }
+        (puzzle, solution)
+    }
+
+    /// Like [Grid::generate], but reject the puzzles which do not match [GenerateOptions::technique_profile]
+    /// This is best-effort: the mix of techniques can not be steered, so puzzles are generated until one matches,
+    /// but no more than [GenerateOptions::max_attempts]. Then the closest one is returned,
+    /// which [TechniqueProfile::matches] tells apart
+    pub fn generate_with_options(
+        rng: &mut impl Rng,
+        options: &GenerateOptions,
+    ) -> (Grid, SolvedGrid) {
+        let Some(profile) = &options.technique_profile else {
+            return Grid::generate(rng);
+        };
+        let mut closest: Option<(f32, (Grid, SolvedGrid))> = None;
+        for _ in 0..options.max_attempts.max(1) {
+            let (puzzle, solution) = Grid::generate(rng);
+            let distance = profile.distance(&puzzle);
+            if distance <= profile.tolerance {
+                return (puzzle, solution);
+            }
+            if closest
+                .as_ref()
+                .is_none_or(|(closest_distance, _)| distance < *closest_distance)
+            {
+                closest = Some((distance, (puzzle, solution)));
+            }
+        }
+        closest.expect("at least one puzzle is generated").1
+    }
+
+    /// Like [Grid::generate], but with exactly [clues] clues: random clues of the solution are given back
+    /// to the generated puzzle, which keeps its solution unique
+    /// Return None if the generated puzzle already has more clues, as none can be removed,
+    /// or if there are fewer than [clues] cells
+    pub fn generate_with_clues(rng: &mut impl Rng, clues: usize) -> Option<(Grid, SolvedGrid)> {
+        let (mut puzzle, solution) = Grid::generate(rng);
+        if !(puzzle.clue_count()..=NB_CELL).contains(&clues) {
+            return None;
+        }
+        let mut empty_cells = puzzle.empty_cells().collect::<Vec<_>>();
+        empty_cells.shuffle(rng);
+        for pos in empty_cells.into_iter().take(clues - puzzle.clue_count()) {
+            puzzle.data[pos] = solution.grid.data[pos];
+        }
+        Some((puzzle, solution))
+    }
+
+    /// A random complete grid, found like [Grid::try_solve] does, except that the digits of each cell are tried
+    /// in a random order instead of ascending
+    /// The same seed always gives the same grid
+    pub fn random_solution(rng: &mut impl Rng) -> SolvedGrid {
+        let digit_orders = Box::new(core::array::from_fn(|_| {
+            let mut order: [Digit; NB_DIGIT] = core::array::from_fn(|i| Digit(i as u8 + 1));
+            order.shuffle(rng);
+            order
+        }));
+        let empty = Grid::empty();
+        let mut solver = empty.try_solve().with_digit_orders(digit_orders);
+        solver.next().expect("the empty grid has solutions")
+    }
+}
+
+// Generating 16×16 puzzles takes too long for a test
+#[cfg(all(test, not(feature = "size-16x16")))]
+mod test {
+    use proptest::prelude::*;
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    use crate::{GenerateOptions, Grid, SolvedGrid, Technique, TechniqueProfile, NB_CELL};
+
+    #[test]
+    fn random_solution_with_same_seed() {
+        let first = Grid::random_solution(&mut SmallRng::seed_from_u64(1));
+        let second = Grid::random_solution(&mut SmallRng::seed_from_u64(1));
+        let other = Grid::random_solution(&mut SmallRng::seed_from_u64(2));
+        assert!(first.grid().is_solved());
+        assert_eq!(first.grid(), second.grid());
+        assert_ne!(first.grid(), other.grid());
+    }
+
+    #[test]
+    fn generated_puzzle_has_unique_solution() {
+        let (puzzle, solution) = Grid::generate(&mut SmallRng::seed_from_u64(1));
+        assert!(puzzle.has_unique_solution());
+        assert!(solution.grid().is_solved());
+        // Panics if a clue of the puzzle is not in the solution
+        solution.annotate(&puzzle);
+
+        // No clue can be removed
+        for pos in (0..NB_CELL).filter(|pos| puzzle.data[*pos].is_some()) {
+            let mut fewer_clues = puzzle.clone();
+            fewer_clues.data[pos] = None;
+            assert!(!fewer_clues.has_unique_solution());
+        }
+    }
+
+    proptest! {
+        // Each case generates a whole puzzle, which is slow in debug builds
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn generated_puzzles_have_a_unique_solution(seed: u64) {
+            let (puzzle, solution) = Grid::generate(&mut SmallRng::seed_from_u64(seed));
+            prop_assert_eq!(puzzle.count_solutions(Some(2)), 1);
+            let solved = puzzle.solve();
+            prop_assert_eq!(solved.as_ref().map(SolvedGrid::grid), Some(solution.grid()));
+        }
+    }
+
+    #[test]
+    fn generate_with_same_seed() {
+        let (first, _) = Grid::generate(&mut SmallRng::seed_from_u64(2));
+        let (second, _) = Grid::generate(&mut SmallRng::seed_from_u64(2));
+        let (other, _) = Grid::generate(&mut SmallRng::seed_from_u64(3));
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn generate_with_clues() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let (puzzle, solution) = Grid::generate_with_clues(&mut rng, NB_CELL - 10).unwrap();
+        assert_eq!(puzzle.clue_count(), NB_CELL - 10);
+        assert!(puzzle.has_unique_solution());
+        solution.annotate(&puzzle);
+
+        assert_eq!(Grid::generate_with_clues(&mut rng, 0), None);
+        assert_eq!(Grid::generate_with_clues(&mut rng, NB_CELL + 1), None);
+    }
+
+    #[test]
+    #[cfg(feature = "size-9x9")]
+    fn generate_with_technique_profile() {
+        // Mostly naked singles, some hidden singles, nothing harder
+        let profile = TechniqueProfile {
+            shares: vec![
+                (Technique::NakedSingle, 0.75),
+                (Technique::HiddenSingle, 0.25),
+            ],
+            tolerance: 0.1,
+        };
+        let options = GenerateOptions {
+            technique_profile: Some(profile.clone()),
+            ..GenerateOptions::default()
+        };
+        for seed in 0..3 {
+            let (puzzle, solution) =
+                Grid::generate_with_options(&mut SmallRng::seed_from_u64(seed), &options);
+            assert!(profile.matches(&puzzle));
+            assert!(puzzle.has_unique_solution());
+            solution.annotate(&puzzle);
+
+            let (_, trace) = puzzle.solve_logically();
+            assert!(trace
+                .iter()
+                .all(|step| step.technique() <= Technique::HiddenSingle));
+        }
+    }
+
+    #[test]
+    fn technique_profile_is_best_effort() {
+        // No puzzle can be solved with X-Wings only
+        let profile = TechniqueProfile {
+            shares: vec![(Technique::XWing, 1.0)],
+            tolerance: 0.1,
+        };
+        let options = GenerateOptions {
+            technique_profile: Some(profile.clone()),
+            max_attempts: 3,
+        };
+        let (puzzle, solution) =
+            Grid::generate_with_options(&mut SmallRng::seed_from_u64(1), &options);
+        assert!(!profile.matches(&puzzle));
+        assert!(puzzle.has_unique_solution());
+        solution.annotate(&puzzle);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{CandidateGrid, Digit, Grid, Unit, NB_CELL, NB_DIGIT};
+
+/// A way to find the digit of a cell without guessing, from the simplest to the hardest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Technique {
+    /// The cell can only hold a single digit
+    NakedSingle,
+    /// The digit can only go in a single cell of a row, a column or a block
+    HiddenSingle,
+    /// A single shows up once [CandidateGrid::apply_naked_pairs] has ruled out some candidates
+    NakedPair,
+    /// A single shows up once [CandidateGrid::apply_pointing] has ruled out some candidates
+    Pointing,
+    /// A single shows up once [CandidateGrid::apply_x_wing] has ruled out some candidates
+    XWing,
+}
+
+/// A technique which rules out candidates, and returns how many it has ruled out
+pub(crate) type Elimination = fn(&mut CandidateGrid) -> usize;
+
+/// The next digit to place, see [Grid::hint]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hint {
+    pub pos: usize,
+    pub digit: Digit,
+    /// The hardest technique needed to find the digit
+    pub technique: Technique,
+}
+
+impl Grid {
+    /// The placement found with the simplest technique, see [Technique], or None if the grid is solved,
+    /// or if every technique is stuck and a guess is needed
+    /// The grid itself is left untouched
+    pub fn hint(&self) -> Option<Hint> {
+        let hint = |(pos, digit), technique| Hint {
+            pos,
+            digit,
+            technique,
+        };
+        if let Some(single) =
+            (0..NB_CELL).find_map(|pos| self.naked_single_at(pos).map(|d| (pos, d)))
+        {
+            return Some(hint(single, Technique::NakedSingle));
+        }
+        let units = [Unit::Row, Unit::Column, Unit::Block]
+            .into_iter()
+            .flat_map(|unit| (0..NB_DIGIT).map(unit));
+        for unit in units {
+            for (d, positions) in Digit::all().zip(self.unit_candidate_positions(unit)) {
+                if let [pos] = positions[..] {
+                    return Some(hint((pos, d), Technique::HiddenSingle));
+                }
+            }
+        }
+
+        // Singles are stuck: rule out candidates with harder and harder techniques until one shows up
+        let eliminations: [(Technique, Elimination); 3] = [
+            (Technique::NakedPair, CandidateGrid::apply_naked_pairs),
+            (Technique::Pointing, CandidateGrid::apply_pointing),
+            (Technique::XWing, CandidateGrid::apply_x_wing),
+        ];
+        let mut candidates = self.candidates();
+        let mut hardest = Technique::NakedPair;
+        loop {
+            let mut eliminated = false;
+            for (technique, apply) in eliminations {
+                if apply(&mut candidates) == 0 {
+                    continue;
+                }
+                eliminated = true;
+                hardest = hardest.max(technique);
+                if let Some(single) = candidates.find_single() {
+                    return Some(hint(single, hardest));
+                }
+            }
+            if !eliminated {
+                return None;
+            }
+        }
+    }
+}
+
+// The fixtures are 9×9 grids
+#[cfg(all(test, feature = "size-9x9"))]
+mod test {
+    use crate::{Grid, Technique};
+
+    /// Solvable with naked singles only
+    const EASY_PUZZLE: &str =
+        "53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79";
+
+    /// Naked singles get stuck on this one, until hidden singles fill some cells
+    const MEDIUM_PUZZLE: &str =
+        ".2.3..9....5..64.2.6..........6.......24.5...5.7..1.8..81.2...........34....13...";
+
+    /// Singles get stuck on this one, until naked pairs rule out some candidates
+    const PAIRS_PUZZLE: &str =
+        "...3..95....49.........8....6791..3..54......1....24..5..8.3.9..4........39.2...7";
+
+    /// Follow the hints until there is none, and return the grid along with the hardest technique used
+    fn follow_hints(puzzle: &str) -> (Grid, Technique) {
+        let mut grid = puzzle.parse::<Grid>().unwrap();
+        let solution = grid.solve().unwrap();
+        let mut hardest = Technique::NakedSingle;
+        while let Some(hint) = grid.hint() {
+            assert_eq!(grid.data[hint.pos], None);
+            assert_eq!(solution.grid().data[hint.pos], Some(hint.digit));
+            grid.data[hint.pos] = Some(hint.digit);
+            hardest = hardest.max(hint.technique);
+        }
+        (grid, hardest)
+    }
+
+    #[test]
+    fn hints_solve_puzzles() {
+        let (grid, hardest) = follow_hints(EASY_PUZZLE);
+        assert!(grid.is_solved());
+        assert_eq!(hardest, Technique::NakedSingle);
+
+        let (grid, hardest) = follow_hints(MEDIUM_PUZZLE);
+        assert!(grid.is_solved());
+        assert_eq!(hardest, Technique::HiddenSingle);
+
+        let (grid, hardest) = follow_hints(PAIRS_PUZZLE);
+        assert!(grid.is_solved());
+        assert_eq!(hardest, Technique::NakedPair);
+    }
+
+    #[test]
+    fn no_hint() {
+        let solved = EASY_PUZZLE.parse::<Grid>().unwrap().solve().unwrap();
+        assert_eq!(solved.grid().hint(), None);
+
+        // Every technique gets stuck on this one from the start
+        let puzzle =
+            "8..........36......7..9.2...5...7.......457.....1...3...1....68..85...1..9....4.."
+                .parse::<Grid>()
+                .unwrap();
+        assert_eq!(puzzle.hint(), None);
+    }
+}
@@ -0,0 +1,289 @@
+use alloc::vec::Vec;
+
+use crate::{Constraint, Digit, Grid, GridSolver, SolvedGrid, NB_CELL};
+
+/// A group of cells of a killer sudoku, whose digits must all be different and add up to [sum]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cage {
+    pub cells: Vec<usize>,
+    pub sum: u32,
+}
+
+/// Why cages could not make a [CageConstraint]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CageError {
+    /// The cell is not in the grid
+    OutOfGrid(usize),
+    /// The cell is in two cages, or twice in the same cage
+    Overlap(usize),
+}
+
+/// The cages of a killer sudoku, which do not overlap: the digits of each cage must all be different
+/// and add up to its sum
+/// The cage of each cell is kept, so that the solver finds it right away
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CageConstraint {
+    cages: Vec<Cage>,
+    cage_of: [Option<usize>; NB_CELL],
+}
+
+impl CageConstraint {
+    /// Fails if a cell of a cage is out of the grid, or in several cages
+    /// The cages do not need to cover the whole grid
+    pub fn new(cages: Vec<Cage>) -> Result<CageConstraint, CageError> {
+        let mut cage_of = [None; NB_CELL];
+        for (i, cage) in cages.iter().enumerate() {
+            for &pos in &cage.cells {
+                let cage_of_pos = cage_of.get_mut(pos).ok_or(CageError::OutOfGrid(pos))?;
+                if cage_of_pos.replace(i).is_some() {
+                    return Err(CageError::Overlap(pos));
+                }
+            }
+        }
+        Ok(CageConstraint { cages, cage_of })
+    }
+
+    pub fn cages(&self) -> &[Cage] {
+        &self.cages
+    }
+}
+
+/// A digit is rejected as soon as its cage can no longer add up to its sum with different digits
+impl Constraint for CageConstraint {
+    fn allows(&self, grid: &Grid, pos: usize, d: Digit) -> bool {
+        let Some(cage) = self.cage_of[pos].map(|i| &self.cages[i]) else {
+            return true;
+        };
+        let mut placed = grid.digits_in_cage(cage);
+        if placed.contains(&d) {
+            return false;
+        }
+        placed.push(d);
+        can_complete(&placed, cage.cells.len() - placed.len(), cage.sum)
+    }
+}
+
+/// A killer sudoku: a grid, usually empty, along with the cages of a [CageConstraint]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KillerGrid {
+    grid: Grid,
+    cages: CageConstraint,
+}
+
+impl KillerGrid {
+    /// Fails if a cell of a cage is out of the grid, or in several cages, see [CageConstraint::new]
+    pub fn new(grid: Grid, cages: Vec<Cage>) -> Result<KillerGrid, CageError> {
+        Ok(KillerGrid {
+            grid,
+            cages: CageConstraint::new(cages)?,
+        })
+    }
+
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    pub fn cages(&self) -> &[Cage] {
+        self.cages.cages()
+    }
+
+    /// Enumerate the solutions which also satisfy the cages, with the same search as [Grid::try_solve]
+    pub fn try_solve(&self) -> GridSolver<'_> {
+        self.grid.try_solve_with_constraints(&[&self.cages])
+    }
+
+    /// Return the first solution, or None if the killer sudoku has no solution
+    pub fn solve(&self) -> Option<SolvedGrid> {
+        self.try_solve().next()
+    }
+}
+
+impl Grid {
+    /// Sum of the digits already placed in the cage, and number of cells of the cage still empty
+    pub fn cage_sum(&self, cage: &Cage) -> (u32, usize) {
+        cage.cells
+            .iter()
+            .fold((0, 0), |(sum, empty), &pos| match self.data[pos] {
+                Some(d) => (sum + u32::from(d.value()), empty),
+                None => (sum, empty + 1),
+            })
+    }
+
+    /// Whether the empty cells of the cage can still be filled with different digits, not already in the cage,
+    /// so that the cage adds up to its target
+    /// Other constraints of the grid are not taken into account
+    pub fn cage_is_satisfiable(&self, cage: &Cage) -> bool {
+        let placed = self.digits_in_cage(cage);
+        if (0..placed.len()).any(|i| placed[..i].contains(&placed[i])) {
+            return false;
+        }
+        let (_, empty) = self.cage_sum(cage);
+        can_complete(&placed, empty, cage.sum)
+    }
+
+    /// The digits already placed in the cage
+    fn digits_in_cage(&self, cage: &Cage) -> Vec<Digit> {
+        cage.cells
+            .iter()
+            .filter_map(|&pos| self.data[pos])
+            .collect()
+    }
+}
+
+/// Whether [empty] more different digits, which are not [placed], can make the digits of a cage add up to [sum]
+/// The [placed] digits must all be different
+fn can_complete(placed: &[Digit], empty: usize, sum: u32) -> bool {
+    let placed_sum = placed.iter().map(|d| u32::from(d.value())).sum();
+    let Some(missing) = sum.checked_sub(placed_sum) else {
+        return false;
+    };
+    let unused = Digit::all()
+        .filter(|d| !placed.contains(d))
+        .map(|d| u32::from(d.value()))
+        .collect::<Vec<_>>();
+    can_reach_sum(&unused, empty, missing)
+}
+
+/// Whether exactly [count] different values taken from [values] can add up to [sum]
+fn can_reach_sum(values: &[u32], count: usize, sum: u32) -> bool {
+    match values.split_first() {
+        _ if count == 0 => sum == 0,
+        None => false,
+        Some((&first, rest)) => {
+            (first <= sum && can_reach_sum(rest, count - 1, sum - first))
+                || can_reach_sum(rest, count, sum)
+        }
+    }
+}
+
+// The fixtures are 4×4 grids
+#[cfg(all(test, feature = "size-4x4"))]
+mod test {
+    use crate::{Cage, CageError, Grid, KillerGrid};
+
+    /// Pairs of cells covering the whole grid, whose only solution is `1234341221434321`
+    fn cages() -> Vec<Cage> {
+        [
+            ([0, 4], 4),
+            ([1, 5], 6),
+            ([2, 3], 7),
+            ([6, 7], 3),
+            ([8, 9], 3),
+            ([10, 14], 6),
+            ([11, 15], 4),
+            ([12, 13], 7),
+        ]
+        .into_iter()
+        .map(|(cells, sum)| Cage {
+            cells: cells.to_vec(),
+            sum,
+        })
+        .collect()
+    }
+
+    #[test]
+    fn solve_killer() {
+        let killer = KillerGrid::new(Grid::empty(), cages()).unwrap();
+        let solution = killer.solve().unwrap();
+        assert!(solution.grid().is_solved());
+        for cage in killer.cages() {
+            assert_eq!(solution.grid().cage_sum(cage), (cage.sum, 0));
+        }
+        assert_eq!(solution.grid().to_line_string(), "1234341221434321");
+        assert_eq!(killer.try_solve().count(), 1);
+
+        // Same solutions as the plain search, filtered by the cages
+        let expected = Grid::empty()
+            .try_solve()
+            .filter(|solution| {
+                killer
+                    .cages()
+                    .iter()
+                    .all(|cage| solution.grid().cage_is_satisfiable(cage))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(killer.try_solve().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn givens_break_a_cage() {
+        // The cage is full of givens, which add up to 5 instead of 4
+        let grid = Grid::from_hex_str("1...4...........").unwrap();
+        let cage = Cage {
+            cells: vec![0, 4],
+            sum: 4,
+        };
+        let killer = KillerGrid::new(grid, vec![cage]).unwrap();
+        let mut solver = killer.try_solve();
+        assert_eq!(solver.next(), None);
+        solver.reset();
+        assert_eq!(solver.next(), None);
+    }
+
+    #[test]
+    fn overlapping_cages() {
+        let mut cages = cages();
+        cages[1].cells.push(4);
+        assert_eq!(
+            KillerGrid::new(Grid::empty(), cages),
+            Err(CageError::Overlap(4))
+        );
+        let mut cages = self::cages();
+        cages[0].cells.push(16);
+        assert_eq!(
+            KillerGrid::new(Grid::empty(), cages),
+            Err(CageError::OutOfGrid(16))
+        );
+    }
+
+    #[test]
+    fn partially_filled_cage() {
+        #[rustfmt::skip]
+        let grid = Grid::from_u8s([
+            1, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+
+        let cage = Cage {
+            cells: vec![0, 1, 4],
+            sum: 8,
+        };
+        assert_eq!(grid.cage_sum(&cage), (1, 2));
+        // 3 + 4
+        assert!(grid.cage_is_satisfiable(&cage));
+
+        let cage = Cage {
+            cells: vec![0, 1, 4],
+            sum: 9,
+        };
+        // 4 + 4 would need a repeated digit
+        assert!(!grid.cage_is_satisfiable(&cage));
+
+        let cage = Cage {
+            cells: vec![0, 1],
+            sum: 1,
+        };
+        assert!(!grid.cage_is_satisfiable(&cage));
+    }
+
+    #[test]
+    fn cage_with_repeated_digit() {
+        #[rustfmt::skip]
+        let grid = Grid::from_u8s([
+            1, 0, 0, 0,
+            0, 0, 0, 1,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+
+        // The cage spans two blocks, so the grid alone does not forbid the repetition
+        let cage = Cage {
+            cells: vec![0, 7],
+            sum: 2,
+        };
+        assert_eq!(grid.cage_sum(&cage), (2, 0));
+        assert!(!grid.cage_is_satisfiable(&cage));
+    }
+}
@@ -0,0 +1,1913 @@
+// Without the `std` feature, only an allocator is needed, see examples/no_std.rs
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use itertools::{Either, Itertools};
+
+mod annotate;
+#[cfg(feature = "std")]
+mod binary;
+#[cfg(feature = "std")]
+mod budget;
+mod cancel;
+mod candidates;
+// Out of reach on 16×16 grids, see [Grid::canonical]
+#[cfg(not(feature = "size-16x16"))]
+mod canonical;
+mod check;
+mod constraint;
+mod difficulty;
+mod display;
+mod dlx;
+mod edit;
+mod game;
+mod generate;
+mod hint;
+mod killer;
+mod logical;
+mod mrv;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod parse;
+mod peers;
+#[cfg(feature = "image")]
+mod png;
+mod replay;
+#[cfg(feature = "serde")]
+mod serialize;
+mod svg;
+mod techniques;
+mod transform;
+mod unit;
+mod variant;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+pub use annotate::{AnnotatedCell, AnnotatedGrid};
+#[cfg(feature = "std")]
+pub use budget::BudgetedSolver;
+pub use cancel::Cancelled;
+pub use candidates::CandidateGrid;
+pub use check::SolutionMistake;
+pub use constraint::{Constraint, StandardConstraint};
+pub use difficulty::Difficulty;
+pub use display::{ConflictDisplay, CoordDisplay};
+pub use edit::{Contradiction, GridEditor, OutOfBounds, SetError};
+pub use game::{Game, Move, MoveError};
+pub use generate::{GenerateOptions, TechniqueProfile};
+pub use hint::{Hint, Technique};
+pub use killer::{Cage, CageConstraint, CageError, KillerGrid};
+pub use logical::SolveStep;
+pub use mrv::MrvSolver;
+pub use parse::{parse_cells, FromNestedError, GridError, ParseError};
+pub use peers::{peers, NB_PEERS};
+pub use replay::{ReplayEvent, ReplayParseError, SolveReplay};
+pub use svg::SvgOptions;
+pub use transform::{BandPermutationError, NotAPermutation};
+pub use unit::{block_indices, column_indices, row_indices, Unit};
+pub use variant::{DiagonalConstraint, Variant};
+#[cfg(feature = "wasm")]
+pub use wasm::solve_line;
+
+#[cfg(not(any(
+    all(
+        feature = "size-4x4",
+        not(feature = "size-9x9"),
+        not(feature = "size-16x16")
+    ),
+    all(
+        not(feature = "size-4x4"),
+        feature = "size-9x9",
+        not(feature = "size-16x16")
+    ),
+    all(
+        not(feature = "size-4x4"),
+        not(feature = "size-9x9"),
+        feature = "size-16x16"
+    ),
+)))]
+compile_error!(
+    "exactly one of the features `size-4x4`, `size-9x9` or `size-16x16` must be enabled"
+);
+
+/// A digit of the grid, in the range `1..=NB_DIGIT`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Digit(u8);
+impl Digit {
+    /// Return None if [value] is not in the range `1..=NB_DIGIT`
+    pub fn new(value: u8) -> Option<Digit> {
+        (1..=NB_DIGIT)
+            .contains(&usize::from(value))
+            .then_some(Digit(value))
+    }
+
+    pub fn value(self) -> u8 {
+        self.0
+    }
+
+    /// Every digit, in increasing order
+    pub fn all() -> impl Iterator<Item = Digit> {
+        Digit::above(0)
+    }
+
+    /// The digits greater than [value], in increasing order
+    /// Unlike skipping the first digits of [Digit::all], the smaller digits are not walked over one by one
+    fn above(value: u8) -> impl Iterator<Item = Digit> {
+        (value + 1..=NB_DIGIT as u8).map(Digit)
+    }
+
+    /// `1`..`9`, then `A`, `B`, ... for digits above 9, as read by [Grid::from_hex_str]
+    fn to_char(self) -> char {
+        match self.0 {
+            value @ 1..=9 => char::from(b'0' + value),
+            value => char::from(b'A' + value - 10),
+        }
+    }
+}
+
+/// The character is not a digit of this grid size, see [Digit::try_from]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotADigit(pub char);
+
+/// `1`..`9`, then `A`, `B`, ... (case insensitive) for digits above 9, as written by [Grid::to_line_string]
+/// Blanks like `.` or `0` are not digits
+impl TryFrom<char> for Digit {
+    type Error = NotADigit;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        let value = match ch {
+            '1'..='9' => ch as u8 - b'0',
+            'A'..='Z' | 'a'..='z' => ch.to_ascii_uppercase() as u8 - b'A' + 10,
+            _ => return Err(NotADigit(ch)),
+        };
+        Digit::new(value).ok_or(NotADigit(ch))
+    }
+}
+
+trait Next: Sized {
+    /// Every digit strictly greater than the one in the cell, in increasing order: all of them for an empty cell
+    fn get_all_next(self) -> impl Iterator<Item = Digit>;
+}
+impl Next for Cell {
+    fn get_all_next(self) -> impl Iterator<Item = Digit> {
+        // Called for every digit tried by the search: no allocation here
+        Digit::above(self.map_or(0, Digit::value))
+    }
+}
+
+/// Side of a block, which is chosen at compile time with one of the `size-4x4`, `size-9x9` or `size-16x16` features
+#[cfg(feature = "size-4x4")]
+pub const BLOCK_SIDE: usize = 2;
+#[cfg(feature = "size-9x9")]
+pub const BLOCK_SIDE: usize = 3;
+#[cfg(feature = "size-16x16")]
+pub const BLOCK_SIDE: usize = 4;
+pub const NB_DIGIT: usize = BLOCK_SIDE * BLOCK_SIDE;
+pub const NB_CELL: usize = NB_DIGIT * NB_DIGIT;
+
+pub type Cell = Option<Digit>;
+
+/// The grid has no solution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolvable;
+
+/// Guarantees that no digit are in direct contradiction
+/// The grid maybe unsolvable though
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid {
+    data: [Cell; NB_CELL],
+}
+
+impl Grid {
+    pub fn empty() -> Grid {
+        Grid {
+            data: [None; NB_CELL],
+        }
+    }
+
+    /// Useful for test to visualize the grid being created
+    /// 0 stand for empty cell
+    /// Other digit stand for themselves
+    /// PANIC if an element is not in the range 0..=NB_CELL
+    #[cfg(test)]
+    fn from_u8s(array: [u8; NB_CELL]) -> Grid {
+        let data = array.map(|c| (c != 0).then(|| Digit::new(c).unwrap()));
+        Grid { data }
+    }
+
+    #[cfg(test)]
+    fn to_u8s(&self) -> [u8; NB_CELL] {
+        self.data.map(|c| c.map_or(0, Digit::value))
+    }
+
+    /// [try_solve] take a [Grid] as mutable reference for performance reason, but guarantees that self has the same value after this function returns
+    pub fn try_solve(&self) -> GridSolver<'_> {
+        GridSolver::from_grid(self)
+    }
+
+    /// Same as [Grid::try_solve], but with forward checking: a digit is not placed if it leaves an empty cell
+    /// without any candidate, so dead-ends are found before the search reaches them
+    /// The solutions are the same, and in the same order, but with much fewer backtracks on constrained puzzles
+    pub fn try_solve_fc(&self) -> GridSolver<'_> {
+        GridSolver::from_grid(self).with_forward_checking()
+    }
+
+    /// Solve the grid, leaving the cells marked in [obstacles] permanently empty
+    /// Units containing an obstacle do not need to hold every digit
+    /// PANIC if an obstacle is not empty
+    pub fn try_solve_with_obstacles(&self, obstacles: [bool; NB_CELL]) -> GridSolver<'_> {
+        assert!(
+            (0..NB_CELL).all(|pos| !obstacles[pos] || self.data[pos].is_none()),
+            "obstacles must be empty cells"
+        );
+        GridSolver::from_grid_and_obstacles(self, obstacles)
+    }
+
+    /// Return the first solution, or None if the grid has no solution
+    pub fn solve(&self) -> Option<SolvedGrid> {
+        self.try_solve().next()
+    }
+
+    /// Solve each of [grids] like [Grid::solve], in the same order, see [Grid::parse_many] to read a collection
+    /// With the `rayon` feature, [Grid::solve_many_parallel] solves them on every core
+    pub fn solve_many(grids: &[Grid]) -> Vec<Option<SolvedGrid>> {
+        grids.iter().map(Grid::solve).collect()
+    }
+
+    /// Return the first solution like [Grid::solve], along with every decision the search made to find it
+    /// Meant to replay the search: [Grid::solve] does not record anything, so it does not pay for the trace
+    pub fn solve_with_trace(&self) -> (Option<SolvedGrid>, Vec<Step>) {
+        let mut solver = self.try_solve();
+        let mut trace = Vec::new();
+        loop {
+            // Cells after the search frontier are empty, except for givens
+            let frontier = solver.psg.fill_until;
+            match solver.step() {
+                StepOutcome::Placed(pos, digit) => trace.push(Step::Place { pos, digit }),
+                StepOutcome::Backtracked(dead_end, pos) => {
+                    let digit =
+                        solver.grid().data[pos].expect("the guessed cell holds its next digit");
+                    trace.push(Step::Backtrack { pos: dead_end });
+                    trace.push(Step::Try { pos, digit });
+                }
+                StepOutcome::Solved => return (Some(SolvedGrid::from_psg(&solver.psg)), trace),
+                StepOutcome::Exhausted => {
+                    // The last dead-end, from which the search could not go back to any guessed cell
+                    if let Some(pos) = (frontier..NB_CELL).find(|pos| self.data[*pos].is_none()) {
+                        trace.push(Step::Backtrack { pos });
+                    }
+                    return (None, trace);
+                }
+            }
+        }
+    }
+
+    /// Count the solutions of the grid, but stop at [limit] if there is one, as there can be astronomically many
+    /// `Some(2)` is enough to know whether the solution is unique
+    pub fn count_solutions(&self, limit: Option<usize>) -> usize {
+        self.try_solve().take(limit.unwrap_or(usize::MAX)).count()
+    }
+
+    /// Whether the grid has exactly one solution
+    /// The search stops as soon as a second solution is found
+    pub fn has_unique_solution(&self) -> bool {
+        let mut solver = self.try_solve();
+        solver.next().is_some() && solver.next().is_none()
+    }
+
+    /// Return the first solution, which proves the grid is solvable, or [Unsolvable] if there is none
+    /// Meant for places, like tests, where the grid must be solvable
+    pub fn assert_solvable(&self) -> Result<SolvedGrid, Unsolvable> {
+        self.solve().ok_or(Unsolvable)
+    }
+
+    /// Whether no cell sharing a row, a column or a block with [pos] holds [d]
+    /// The cell at [pos] itself is not looked at
+    fn can_accept_digit_at_pos(&self, d: Digit, pos: usize) -> bool {
+        peers(pos).iter().all(|&peer| self.data[peer] != Some(d))
+    }
+
+    /// Whether no digit is twice in a row, a column or a block, whether the grid is complete or not
+    /// Only grids that were modified bypassing [can_accept_digit_at_pos], like deserialized or edited ones,
+    /// can be invalid
+    pub fn is_valid(&self) -> bool {
+        // can_accept_digit_at_pos does not look at the cell itself, so it does not need to be cleared
+        (0..NB_CELL).all(|pos| match self.data[pos] {
+            None => true,
+            Some(d) => self.can_accept_digit_at_pos(d, pos),
+        })
+    }
+
+    /// Whether every cell is filled, and no digit contradicts another one
+    pub fn is_solved(&self) -> bool {
+        self.data.iter().all(Option::is_some) && self.is_valid()
+    }
+
+    /// Position of every empty cell, in row-major order
+    pub fn empty_cells(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..NB_CELL).filter(|pos| self.data[*pos].is_none())
+    }
+
+    /// Position and digit of every filled cell, in row-major order
+    pub fn filled_cells(&self) -> impl Iterator<Item = (usize, Digit)> + '_ {
+        self.data
+            .iter()
+            .enumerate()
+            .filter_map(|(pos, cell)| cell.map(|d| (pos, d)))
+    }
+
+    /// Number of filled cells, which are the clues of a puzzle
+    pub fn clue_count(&self) -> usize {
+        self.filled_cells().count()
+    }
+
+    /// Number of empty cells, so that `clue_count() + empty_count() == NB_CELL`
+    pub fn empty_count(&self) -> usize {
+        self.empty_cells().count()
+    }
+
+    /// If exactly one cell is empty and only one digit can go there, return them: placing it completes the grid
+    pub fn one_from_solved(&self) -> Option<(usize, Digit)> {
+        let (pos,) = self.empty_cells().collect_tuple()?;
+        let (d,) = Digit::all()
+            .filter(|d| self.can_accept_digit_at_pos(*d, pos))
+            .collect_tuple()?;
+        Some((pos, d))
+    }
+
+    /// The cells, row by row
+    pub fn to_grid_2d(&self) -> [[Cell; NB_DIGIT]; NB_DIGIT] {
+        core::array::from_fn(|row| core::array::from_fn(|column| self[(row, column)]))
+    }
+}
+
+fn times(n: usize) -> impl Iterator {
+    core::iter::repeat_n((), n)
+}
+/// Index of the cell at [row] and [column] in the row-major [Grid::data]
+/// PANIC if the row or the column is out of the grid
+fn cell_index(row: usize, column: usize) -> usize {
+    assert!(
+        row < NB_DIGIT && column < NB_DIGIT,
+        "({}, {}) is out of the grid",
+        row,
+        column
+    );
+    row * NB_DIGIT + column
+}
+
+/// The empty grid, like [Grid::empty]
+impl Default for Grid {
+    fn default() -> Self {
+        Grid::empty()
+    }
+}
+
+/// The cell at (row, column), both starting from 0
+/// PANIC if the row or the column is out of the grid
+impl core::ops::Index<(usize, usize)> for Grid {
+    type Output = Cell;
+
+    fn index(&self, (row, column): (usize, usize)) -> &Cell {
+        &self.data[cell_index(row, column)]
+    }
+}
+
+/// Unlike [Grid::edit], nothing prevents writing a digit which contradicts another one: check with [Grid::is_valid]
+impl core::ops::IndexMut<(usize, usize)> for Grid {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Cell {
+        &mut self.data[cell_index(row, column)]
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_boxed(f, DisplayStyle::Unicode, Digit::to_char)
+    }
+}
+
+/// The characters used to draw the borders of a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DisplayStyle {
+    /// Box-drawing characters, like `┌`, `│` or `┼`
+    Unicode,
+    /// `+`, `-` and `|` only, for terminals and logs which mangle box-drawing characters
+    Ascii,
+}
+
+struct Borders {
+    top_left_corner: char,
+    top_right_corner: char,
+    bottom_right_corner: char,
+    bottom_left_corner: char,
+    horizontal_border: char,
+    vertical_border: char,
+    up_tee: &'static str,
+    right_tee: char,
+    down_tee: &'static str,
+    left_tee: char,
+    cross: &'static str,
+}
+
+impl DisplayStyle {
+    fn borders(self) -> Borders {
+        match self {
+            DisplayStyle::Unicode => Borders {
+                top_left_corner: '┌',
+                top_right_corner: '┐',
+                bottom_right_corner: '┘',
+                bottom_left_corner: '└',
+                horizontal_border: '─',
+                vertical_border: '│',
+                up_tee: "┬",
+                right_tee: '┤',
+                down_tee: "┴",
+                left_tee: '├',
+                cross: "┼",
+            },
+            DisplayStyle::Ascii => Borders {
+                top_left_corner: '+',
+                top_right_corner: '+',
+                bottom_right_corner: '+',
+                bottom_left_corner: '+',
+                horizontal_border: '-',
+                vertical_border: '|',
+                up_tee: "+",
+                right_tee: '+',
+                down_tee: "+",
+                left_tee: '+',
+                cross: "+",
+            },
+        }
+    }
+}
+
+/// Display a [Grid] with a custom symbol per digit, see [Grid::display_with_symbols]
+pub struct SymbolDisplay<'a> {
+    grid: &'a Grid,
+    symbols: &'a [char; NB_DIGIT],
+}
+
+impl fmt::Display for SymbolDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.grid.write_boxed(f, DisplayStyle::Unicode, |d| {
+            self.symbols[usize::from(d.value()) - 1]
+        })
+    }
+}
+
+impl Grid {
+    /// Display the grid like its [fmt::Display] implementation, but with [symbols] instead of decimal digits:
+    /// the first symbol stands for the digit 1, and so on
+    /// Empty cells are still shown as `.`
+    pub fn display_with_symbols<'a>(&'a self, symbols: &'a [char; NB_DIGIT]) -> SymbolDisplay<'a> {
+        SymbolDisplay {
+            grid: self,
+            symbols,
+        }
+    }
+
+    /// Same as the [fmt::Display] implementation, but with ASCII characters only:
+    /// `+` for corners and junctions, `-` and `|` for borders
+    pub fn to_ascii_string(&self) -> String {
+        let mut s = String::new();
+        self.write_boxed(&mut s, DisplayStyle::Ascii, Digit::to_char)
+            .expect("writing to a String can not fail");
+        s
+    }
+
+    /// Draw the grid with the borders of [style], each digit being shown as [digit_char]
+    fn write_boxed(
+        &self,
+        f: &mut impl fmt::Write,
+        style: DisplayStyle,
+        digit_char: impl Fn(Digit) -> char,
+    ) -> fmt::Result {
+        self.write_boxed_cells(f, style, 1, |pos| {
+            self.data[pos].map_or('.', &digit_char).to_string()
+        })
+    }
+
+    /// Draw the grid with the borders of [style], each cell being shown as [cell_str] of its position,
+    /// which must be [cell_width] characters wide
+    #[allow(unstable_name_collisions)]
+    fn write_boxed_cells(
+        &self,
+        f: &mut impl fmt::Write,
+        style: DisplayStyle,
+        cell_width: usize,
+        cell_str: impl Fn(usize) -> String,
+    ) -> fmt::Result {
+        use itertools::Itertools;
+
+        let Borders {
+            top_left_corner,
+            top_right_corner,
+            bottom_right_corner,
+            bottom_left_corner,
+            horizontal_border,
+            vertical_border,
+            up_tee,
+            right_tee,
+            down_tee,
+            left_tee,
+            cross,
+        } = style.borders();
+
+        const NB_BLOCK: usize = BLOCK_SIDE;
+
+        let line_length =
+        // All cells will be on the line
+        NB_DIGIT * cell_width
+        // As many separator as blocks
+        + NB_BLOCK
+        // end of block
+        + 1
+        // new line
+        + 1;
+
+        // TODO: allocate only the right amount, then only use push or push_str, but od not create extra String
+        let mut s = String::with_capacity(line_length * line_length);
+
+        // str::from_utf8(HORIZONTAL_TEE)
+        // vec!["ds", "fds"].iter().as_slice().join(sep);
+
+        // First border line
+        s.push(top_left_corner);
+        s.push_str(
+            &times(NB_BLOCK)
+                .map(|_| {
+                    times(BLOCK_SIDE * cell_width)
+                        .map(|_| horizontal_border)
+                        .join("")
+                })
+                .join(up_tee),
+        );
+        s.push(top_right_corner);
+        s.push('\n');
+
+        let horizontal_border_line = {
+            let mut s = left_tee.to_string();
+            s.push_str(
+                &times(BLOCK_SIDE)
+                    .map(|_| {
+                        times(BLOCK_SIDE * cell_width)
+                            .map(|_| horizontal_border)
+                            .join("")
+                    })
+                    .join(cross),
+            );
+            s.push(right_tee);
+            s.push('\n');
+            s
+        };
+
+        let body = (0..NB_BLOCK)
+            .map(|block_y_index| {
+                (0..BLOCK_SIDE)
+                    .map(|line_in_block| {
+                        let line = block_y_index * BLOCK_SIDE + line_in_block;
+                        let mut number_line = String::new();
+                        number_line.push(vertical_border);
+                        let number_line_body = (0..NB_BLOCK)
+                            .map(|block_x_index| {
+                                (0..BLOCK_SIDE)
+                                    .map(|column_in_block| {
+                                        let column = block_x_index * BLOCK_SIDE + column_in_block;
+                                        cell_str(cell_index(line, column))
+                                    })
+                                    .join("")
+                            })
+                            .join(&vertical_border.to_string());
+                        number_line.push_str(&number_line_body);
+
+                        number_line.push(vertical_border);
+                        number_line.push('\n');
+
+                        number_line
+                    })
+                    .join("")
+            })
+            .join(&horizontal_border_line);
+
+        s.push_str(&body);
+
+        // Bottom border line
+        s.push(bottom_left_corner);
+        s.push_str(
+            &times(NB_BLOCK)
+                .map(|_| {
+                    times(BLOCK_SIDE * cell_width)
+                        .map(|_| horizontal_border)
+                        .join("")
+                })
+                .join(down_tee),
+        );
+        s.push(bottom_right_corner);
+        s.push('\n');
+
+        f.write_str(&s)
+    }
+}
+
+/// All Cell in [grid] strictly before the cell at index [fill_until] are filled, except [obstacles]
+/// Cell after fill_until may or may not be filled
+/// All cells are guaranteed to not contradict with each other, per [Grid] guarantee
+///
+/// The search works in place on this single grid: backtracking empties the guessed cells it goes back over,
+/// so the only copies of a grid are the one made when the solver is created, and one per solution returned
+struct PartialySolvedGrid<'a> {
+    grid: Grid,
+    fill_until: usize,
+    /// Cells which are permanently empty. They are not filled, and do not need to be for the grid to be solved
+    obstacles: [bool; NB_CELL],
+    used: UsedDigits,
+    /// The order in which the digits are tried in each cell, ascending if None
+    digit_orders: Option<Box<[[Digit; NB_DIGIT]; NB_CELL]>>,
+    /// Whether a digit is rejected when it would leave an empty peer without any candidate,
+    /// instead of only when the search reaches that peer
+    forward_checking: bool,
+    /// The rules every digit must also follow, see [Grid::try_solve_with_constraints]
+    constraints: Vec<Box<dyn Constraint + 'a>>,
+    /// How many digits placed by the search are currently in the grid
+    depth: usize,
+    stats: SolverStats,
+}
+
+/// Counters of the work done by a [GridSolver] so far, see [GridSolver::stats]
+/// Comparing them between puzzles gives a rough idea of how hard the puzzles are for the backtracking search
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SolverStats {
+    /// How many times a digit has been placed in an empty cell, including the next digits tried in a cell
+    pub placements: usize,
+    /// How many times every digit has failed in a guessed cell, so that the search went back to an earlier cell
+    pub backtracks: usize,
+    /// The largest number of digits placed by the search at the same time, givens excluded
+    pub max_depth: usize,
+}
+
+/// The digits of each row, column and block, as bit masks where the digit `d` is the bit `d - 1`
+/// They are kept up to date as digits are placed and removed, so that checking a digit is a single bit test
+/// instead of a scan of the peers of the cell
+struct UsedDigits {
+    in_rows: [u16; NB_DIGIT],
+    in_columns: [u16; NB_DIGIT],
+    in_blocks: [u16; NB_DIGIT],
+}
+
+impl UsedDigits {
+    fn new(grid: &Grid) -> UsedDigits {
+        let mut used = UsedDigits {
+            in_rows: [0; NB_DIGIT],
+            in_columns: [0; NB_DIGIT],
+            in_blocks: [0; NB_DIGIT],
+        };
+        for (pos, cell) in grid.data.into_iter().enumerate() {
+            if let Some(d) = cell {
+                used.add(d, pos);
+            }
+        }
+        used
+    }
+
+    /// The row, column and block of the cell at [pos]
+    fn units_of(pos: usize) -> (usize, usize, usize) {
+        let (row, column) = (pos / NB_DIGIT, pos % NB_DIGIT);
+        (
+            row,
+            column,
+            row / BLOCK_SIDE * BLOCK_SIDE + column / BLOCK_SIDE,
+        )
+    }
+
+    /// The digits that can go in the cell at [pos], as a bit mask
+    fn candidates(&self, pos: usize) -> u16 {
+        let (row, column, block) = UsedDigits::units_of(pos);
+        let all_digits = u16::MAX >> (16 - NB_DIGIT);
+        all_digits & !(self.in_rows[row] | self.in_columns[column] | self.in_blocks[block])
+    }
+
+    /// Same as [Grid::can_accept_digit_at_pos], using the bit masks
+    fn accepts(&self, d: Digit, pos: usize) -> bool {
+        self.candidates(pos) & (1 << (d.value() - 1)) != 0
+    }
+
+    fn add(&mut self, d: Digit, pos: usize) {
+        let (row, column, block) = UsedDigits::units_of(pos);
+        let bit = 1 << (d.value() - 1);
+        self.in_rows[row] |= bit;
+        self.in_columns[column] |= bit;
+        self.in_blocks[block] |= bit;
+    }
+
+    fn remove(&mut self, d: Digit, pos: usize) {
+        let (row, column, block) = UsedDigits::units_of(pos);
+        let bit = !(1 << (d.value() - 1));
+        self.in_rows[row] &= bit;
+        self.in_columns[column] &= bit;
+        self.in_blocks[block] &= bit;
+    }
+}
+
+impl<'a> PartialySolvedGrid<'a> {
+    fn new(grid: Grid, fill_until: usize, obstacles: [bool; NB_CELL]) -> PartialySolvedGrid<'a> {
+        PartialySolvedGrid {
+            used: UsedDigits::new(&grid),
+            grid,
+            fill_until,
+            obstacles,
+            digit_orders: None,
+            forward_checking: false,
+            constraints: Vec::new(),
+            depth: 0,
+            stats: SolverStats::default(),
+        }
+    }
+
+    /// The digits to try in the cell at [pos] once [current] has failed, in the order of the search
+    fn next_digits(&self, pos: usize, current: Cell) -> impl Iterator<Item = Digit> + '_ {
+        match &self.digit_orders {
+            None => Either::Left(current.get_all_next()),
+            Some(orders) => {
+                let order = &orders[pos];
+                let skipped = current.map_or(0, |current| {
+                    order
+                        .iter()
+                        .position(|d| *d == current)
+                        .expect("every digit is in the order")
+                        + 1
+                });
+                Either::Right(order[skipped..].iter().copied())
+            }
+        }
+    }
+
+    fn can_place(&self, d: Digit, pos: usize) -> bool {
+        self.used.accepts(d, pos)
+            && (!self.forward_checking || self.leaves_candidates_to_peers(d, pos))
+            && self
+                .constraints
+                .iter()
+                .all(|constraint| constraint.allows(&self.grid, pos, d))
+    }
+
+    /// Whether every empty peer of [pos] would still have a candidate once [d] is placed at [pos]
+    fn leaves_candidates_to_peers(&self, d: Digit, pos: usize) -> bool {
+        let bit = 1 << (d.value() - 1);
+        peers(pos).iter().all(|&peer| {
+            self.grid.data[peer].is_some()
+                || self.obstacles[peer]
+                || self.used.candidates(peer) & !bit != 0
+        })
+    }
+
+    fn place(&mut self, d: Digit, pos: usize) {
+        self.used.add(d, pos);
+        self.grid.data[pos] = Some(d);
+        self.depth += 1;
+        self.stats.placements += 1;
+        self.stats.max_depth = self.stats.max_depth.max(self.depth);
+    }
+
+    fn remove(&mut self, pos: usize) -> Cell {
+        let d = self.grid.data[pos].take()?;
+        self.used.remove(d, pos);
+        self.depth -= 1;
+        Some(d)
+    }
+
+    fn try_fill_next_cell(&mut self) -> bool {
+        if self.fill_until == self.grid.data.len() {
+            return false;
+        }
+        match self.grid.data[self.fill_until] {
+            Some(_) => {
+                // a digit is already here
+                self.fill_until += 1;
+                true
+            }
+            None if self.obstacles[self.fill_until] => {
+                // this cell must stay empty
+                self.fill_until += 1;
+                true
+            }
+            None => {
+                let pos = self.fill_until;
+                let digit = self
+                    .next_digits(pos, None)
+                    .find(|d| self.can_place(*d, pos));
+                if let Some(d) = digit {
+                    self.place(d, pos);
+                    self.fill_until += 1;
+                    return true;
+                }
+                // No digit can fit in the first empty cell. We should backtrack
+                false
+            }
+        }
+    }
+
+    /// Replace the digit of the guessed cell at [pos] with the next one which fits, and return true,
+    /// or leave the cell empty and return false if there is none
+    /// [GridSolver::backtrack] calls it on the guessed cells from the last one, so every guessed cell after [pos]
+    /// has already been emptied, and the cells in between are givens or obstacles
+    fn try_increment_cell_at_index(&mut self, pos: usize) -> bool {
+        let current = self.remove(pos);
+        let next = self
+            .next_digits(pos, current)
+            .find(|d| self.can_place(*d, pos));
+        if let Some(d) = next {
+            self.place(d, pos);
+            return true;
+        }
+        // Every cell from here on is empty, or a given or an obstacle: the search resumes from this cell,
+        // even if it is not the last one filled before the dead-end
+        self.fill_until = pos;
+        self.stats.backtracks += 1;
+        false
+    }
+}
+
+impl fmt::Display for PartialySolvedGrid<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.grid.fmt(f)
+    }
+}
+
+/// The solver ran out of time before finding the next solution, or proving there is none,
+/// see [GridSolver::with_time_budget]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeout;
+
+/// How many progress the solver makes by default between two checks of whether it should stop
+const STOP_CHECK_INTERVAL: usize = 1024;
+
+/// Enumerate the solutions of a grid, one at a time
+/// The solver only holds the grid being filled and a few flags and indices, and keeps nothing from the solutions
+/// it returned: its memory does not depend on the number of solutions, so even astronomically many can be streamed
+pub struct GridSolver<'a> {
+    initial_grid: &'a Grid,
+    psg: PartialySolvedGrid<'a>,
+    /// Set once the search space has been fully explored, so that the solver does not start over
+    exhausted: bool,
+    /// Set by [GridSolver::step] once [StepOutcome::Solved] has been returned for the current solution
+    solution_reported: bool,
+}
+
+/// What happened during a single [GridSolver::step]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepOutcome {
+    /// The digit has been placed in the empty cell at the given position
+    Placed(usize, Digit),
+    /// The solver hit a dead-end at the first position, and went back to the guessed cell at the second position,
+    /// which now holds its next possible digit
+    Backtracked(usize, usize),
+    /// The grid is now complete. The next step will look for another solution
+    Solved,
+    /// There is no more solution to be found. Any further step will also return [StepOutcome::Exhausted]
+    Exhausted,
+}
+
+/// A decision of the search, as recorded by [Grid::solve_with_trace]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// The digit has been placed in an empty cell reached for the first time
+    Place { pos: usize, digit: Digit },
+    /// No digit can go in the empty cell at [pos]: the search goes back to the previous guessed cells
+    Backtrack { pos: usize },
+    /// After a [Step::Backtrack], the guessed cell at [pos] now holds its next possible digit
+    Try { pos: usize, digit: Digit },
+}
+
+impl<'a> GridSolver<'a> {
+    fn from_grid(grid: &'a Grid) -> GridSolver<'a> {
+        GridSolver::from_grid_and_obstacles(grid, [false; NB_CELL])
+    }
+
+    fn from_grid_and_obstacles(grid: &'a Grid, obstacles: [bool; NB_CELL]) -> GridSolver<'a> {
+        // Leading givens and obstacles need no search: skip them right away,
+        // so that an already complete grid is its own solution without any progress being made
+        let fill_until = (0..NB_CELL)
+            .find(|pos| grid.data[*pos].is_none() && !obstacles[*pos])
+            .unwrap_or(NB_CELL);
+        GridSolver {
+            initial_grid: grid,
+            psg: PartialySolvedGrid::new(grid.clone(), fill_until, obstacles),
+            // The givens are not checked by the search, so if they already contradict each other,
+            // like in an invalid complete grid, there is no solution
+            exhausted: !grid.is_valid(),
+            solution_reported: false,
+        }
+    }
+
+    /// Start the search over from the initial grid, even once exhausted: the same solutions are found again,
+    /// in the same order
+    /// The obstacles and the search options are kept, but [GridSolver::stats] start from zero again
+    pub fn reset(&mut self) {
+        let mut solver = GridSolver::from_grid_and_obstacles(self.initial_grid, self.psg.obstacles);
+        solver.psg.forward_checking = self.psg.forward_checking;
+        solver.psg.digit_orders = self.psg.digit_orders.take();
+        for constraint in core::mem::take(&mut self.psg.constraints) {
+            solver = solver.with_constraint(constraint);
+        }
+        *self = solver;
+    }
+
+    /// Reject a digit as soon as it leaves an empty cell without any candidate, see [Grid::try_solve_fc]
+    fn with_forward_checking(mut self) -> Self {
+        self.psg.forward_checking = true;
+        self
+    }
+
+    /// Also check [constraint] for every digit placed, see [Grid::try_solve_with_constraints]
+    /// The givens are not checked by the search, so if they already break the constraint, there is no solution
+    fn with_constraint(mut self, constraint: Box<dyn Constraint + 'a>) -> Self {
+        if !self.initial_grid.givens_follow(&*constraint) {
+            self.exhausted = true;
+        }
+        self.psg.constraints.push(constraint);
+        self
+    }
+
+    /// Try the digits of each cell in the given order, instead of ascending
+    fn with_digit_orders(mut self, digit_orders: Box<[[Digit; NB_DIGIT]; NB_CELL]>) -> Self {
+        self.psg.digit_orders = Some(digit_orders);
+        self
+    }
+
+    /// The grid as currently filled by the solver
+    pub fn grid(&self) -> &Grid {
+        &self.psg.grid
+    }
+
+    /// The work done by the search so far, accumulated over all the solutions found
+    pub fn stats(&self) -> &SolverStats {
+        &self.psg.stats
+    }
+
+    /// Make the smallest possible amount of progress in the search, and report it
+    /// Given cells and obstacles are not a decision of the solver, so they are skipped silently
+    pub fn step(&mut self) -> StepOutcome {
+        loop {
+            if self.exhausted {
+                return StepOutcome::Exhausted;
+            }
+            if self.psg.fill_until == NB_CELL && !self.solution_reported {
+                self.solution_reported = true;
+                return StepOutcome::Solved;
+            }
+            self.solution_reported = false;
+
+            let pos = self.psg.fill_until;
+            if !self.psg.try_fill_next_cell() {
+                return match self.backtrack() {
+                    Some(guessed_cell) => StepOutcome::Backtracked(pos, guessed_cell),
+                    None => StepOutcome::Exhausted,
+                };
+            }
+            if self.initial_grid.data[pos].is_none() && !self.psg.obstacles[pos] {
+                let digit = self.psg.grid.data[pos].expect("the cell has just been filled");
+                return StepOutcome::Placed(pos, digit);
+            }
+        }
+    }
+
+    // Either fill the next cell, or backtrack until a previous cell can be incremented
+    // If we see the grid digit in a list and interpret that as a number (empty cell meaning 0),
+    // then this number after this function should be strictly greather than before calling the function
+    // Return if a progress has been made
+    // Returning false mean there is no more solution to be found
+    fn make_progress(&mut self) -> bool {
+        if self.exhausted {
+            return false;
+        }
+        match self.psg.try_fill_next_cell() {
+            // The cell has been filled, continue this way
+            true => true,
+            // No cell could have been filled: we are at a dead-end: backtrack
+            false => self.backtrack().is_some(),
+        }
+    }
+
+    /// Increment the last guessed cell that can be incremented, and return its index
+    /// Return None if no guessed cell could be incremented, meaning there is no more solution
+    fn backtrack(&mut self) -> Option<usize> {
+        // Walk the guessed cells lazily, from the last one: allocating their list on every backtrack is costly
+        let initial_grid = self.initial_grid;
+        let guessed_cells = (0..self.psg.fill_until)
+            .rev()
+            // Only keep the cell which were empty in the initial grid
+            .filter(|cell_index| initial_grid.data[*cell_index].is_none());
+        for guessed_cell in guessed_cells {
+            // Obstacles are never filled
+            if self.psg.obstacles[guessed_cell] {
+                continue;
+            }
+            if self.psg.try_increment_cell_at_index(guessed_cell) {
+                // the last guessed cell has been incremented,
+                // TODO: break out of the little loop, but stay inside the big loop
+                return Some(guessed_cell);
+            }
+        }
+        // Could not increment any of the already filled cells
+        // We already know that the next cannot be filled either
+        // There is no more solution
+        self.exhausted = true;
+        None
+    }
+
+    /// Like [Iterator::next], but give up with [Timeout] as soon as [should_stop] returns true
+    /// The search can be resumed later by calling this function again
+    /// [should_stop] is only called once every [check_interval] progress, because it may be costly
+    fn next_or_stop(
+        &mut self,
+        check_interval: usize,
+        mut should_stop: impl FnMut() -> bool,
+    ) -> Result<Option<SolvedGrid>, Timeout> {
+        // The only way out of this loop is to either:
+        // - return a possible solution
+        // - exhaust all possible solution, then return
+        // - be asked to stop
+        let mut iteration: usize = 0;
+        loop {
+            if iteration.is_multiple_of(check_interval) && should_stop() {
+                return Err(Timeout);
+            }
+            iteration = iteration.wrapping_add(1);
+
+            if self.psg.fill_until == NB_CELL && !self.exhausted {
+                let result = SolvedGrid::from_psg(&self.psg);
+                self.make_progress();
+                return Ok(Some(result));
+            }
+
+            if !self.make_progress() {
+                return Ok(None);
+            }
+        }
+    }
+}
+
+impl Iterator for GridSolver<'_> {
+    type Item = SolvedGrid;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_or_stop(STOP_CHECK_INTERVAL, || false)
+            .expect("the search is never asked to stop")
+    }
+}
+
+/// Like PartiallySolvedGrid, but with fill_until = NB_CELL
+/// So:
+///  - No cell contradict each other
+///  - All cells are filled, except obstacles if the grid was solved with some
+///
+/// So the grid is solved
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SolvedGrid {
+    grid: Grid,
+    // data: [Digit; NB_CELL],
+}
+
+impl SolvedGrid {
+    /// The complete grid
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    /// The digits, row by row
+    /// PANIC if the grid was solved with obstacles, which stay empty
+    pub fn to_grid_2d(&self) -> [[Digit; NB_DIGIT]; NB_DIGIT] {
+        self.grid
+            .to_grid_2d()
+            .map(|row| row.map(|cell| cell.expect("a solved grid has no empty cell")))
+    }
+
+    fn from_psg(psg: &PartialySolvedGrid) -> SolvedGrid {
+        assert_eq!(psg.fill_until, NB_CELL);
+        psg.grid
+            .data
+            .iter()
+            .zip(psg.obstacles)
+            .for_each(|(c, obstacle)| assert!(c.is_some() || obstacle));
+
+        SolvedGrid {
+            grid: psg.grid.clone(),
+        }
+        // SolvedGrid {
+        //     data: psg.grid.data.map(|maybe_digit| maybe_digit.expect("Because fill_until == NB_CELL, and data.len() == fill_until, digit should always be Some"))
+        // }
+    }
+    // fn from(grid: Grid) -> SolvedGrid {
+    //     SolvedGrid {
+    //         data: grid.data.map(|maybe_digit| maybe_digit.value.unwrap())
+    //     }
+    // }
+}
+
+impl fmt::Display for SolvedGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.grid.fmt(f)
+    }
+}
+
+// The fixtures are 4×4 grids
+#[cfg(all(test, feature = "size-4x4"))]
+mod test {
+    use itertools::Itertools;
+
+    use crate::{
+        times, CandidateGrid, Digit, Grid, GridSolver, Next, SolverStats, Step, StepOutcome,
+        Unsolvable, NB_CELL, NB_DIGIT,
+    };
+
+    #[test]
+    fn digit_next() {
+        assert_eq!(Some(Digit::new(2).unwrap()).get_all_next().count(), 2);
+        assert_eq!(None.get_all_next().count(), 4);
+        assert_eq!(
+            Some(Digit::new(2).unwrap())
+                .get_all_next()
+                .map(Digit::value)
+                .collect_vec(),
+            [3, 4]
+        );
+        assert_eq!(Some(Digit::new(4).unwrap()).get_all_next().count(), 0);
+    }
+
+    #[test]
+    fn iter_solutions() {
+        let grid = Grid::empty();
+        let mut solver = grid.try_solve();
+
+        let first_solution = solver.next().unwrap();
+
+        #[rustfmt::skip]
+        let expected = Grid::from_u8s([
+            1, 2, 3, 4,
+            3, 4, 1, 2,
+            2, 1, 4, 3,
+            4, 3, 2, 1
+        ]);
+        assert_eq!(
+            first_solution.grid,
+            expected,
+            "{:?}",
+            first_solution.unit_strings()
+        );
+
+        let second_solution = solver.next().unwrap();
+
+        println!("{}", &second_solution);
+        dbg!(second_solution.grid.to_u8s());
+
+        #[rustfmt::skip]
+        let expected = Grid::from_u8s([
+            1, 2, 3, 4,
+            3, 4, 1, 2,
+            2, 3, 4, 1,
+            4, 1, 2, 3
+        ]);
+        assert_eq!(
+            second_solution.grid,
+            expected,
+            "{:?}",
+            second_solution.unit_strings()
+        );
+    }
+
+    /// Check that the cells before fill_until are filled, except obstacles, that the guessed cells from fill_until
+    /// on are empty, and that the givens are untouched
+    fn assert_fill_until_is_consistent(solver: &GridSolver) {
+        let psg = &solver.psg;
+        for pos in 0..NB_CELL {
+            let cell = psg.grid.data[pos];
+            match solver.initial_grid.data[pos] {
+                Some(given) => assert_eq!(cell, Some(given), "given changed at {}", pos),
+                None if psg.obstacles[pos] || pos >= psg.fill_until => {
+                    assert_eq!(cell, None, "cell {} filled from {}", pos, psg.fill_until)
+                }
+                None => assert!(
+                    cell.is_some(),
+                    "cell {} empty before {}",
+                    pos,
+                    psg.fill_until
+                ),
+            }
+        }
+        assert!(psg.grid.is_valid());
+    }
+
+    /// Step through the whole search of [solver], checking fill_until after each step,
+    /// and return the solutions, along with whether any backtrack went over a guessed cell
+    fn step_through(mut solver: GridSolver) -> (Vec<Grid>, bool) {
+        let mut solutions = Vec::new();
+        let mut backtracked_over_guess = false;
+        loop {
+            let outcome = solver.step();
+            assert_fill_until_is_consistent(&solver);
+            match outcome {
+                StepOutcome::Solved => solutions.push(solver.grid().clone()),
+                StepOutcome::Backtracked(dead_end, guessed_cell) => {
+                    backtracked_over_guess |= (guessed_cell + 1..dead_end).any(|pos| {
+                        solver.initial_grid.data[pos].is_none() && !solver.psg.obstacles[pos]
+                    });
+                }
+                StepOutcome::Exhausted => return (solutions, backtracked_over_guess),
+                StepOutcome::Placed(..) => {}
+            }
+        }
+    }
+
+    #[test]
+    fn backtrack_over_several_guessed_cells() {
+        // Givens interspersed with the guessed cells
+        let puzzle = Grid::from_hex_str("....43.1.4......").unwrap();
+        let (solutions, backtracked_over_guess) = step_through(puzzle.try_solve());
+        assert!(backtracked_over_guess);
+        assert_eq!(
+            solutions,
+            puzzle.try_solve().map(|s| s.grid).collect::<Vec<_>>()
+        );
+        assert_eq!(solutions.len(), puzzle.count_solutions_dlx(None));
+
+        let (solutions, backtracked_over_guess) = step_through(Grid::empty().try_solve());
+        assert!(backtracked_over_guess);
+        assert_eq!(solutions.len(), 288);
+
+        // Obstacles between the guessed cells
+        let mut obstacles = [false; NB_CELL];
+        obstacles[1] = true;
+        obstacles[6] = true;
+        let puzzle = Grid::from_hex_str("1.........3....2").unwrap();
+        let (solutions, _) = step_through(puzzle.try_solve_with_obstacles(obstacles));
+        assert_eq!(
+            solutions,
+            puzzle
+                .try_solve_with_obstacles(obstacles)
+                .map(|s| s.grid)
+                .collect::<Vec<_>>()
+        );
+        assert!(!solutions.is_empty());
+
+        // A dead-end right after the givens
+        let puzzle = Grid::from_hex_str("12....3...4.....").unwrap();
+        assert_eq!(step_through(puzzle.try_solve()).0, []);
+    }
+
+    #[test]
+    fn make_progress_on_full_grid() {
+        let grid = Grid::empty();
+        let mut solver = grid.try_solve();
+
+        times(NB_CELL).for_each(|_| assert!(solver.make_progress()));
+
+        assert_eq!(solver.psg.fill_until, NB_CELL);
+        println!("{}", solver.psg);
+
+        assert!(solver.make_progress());
+
+        #[rustfmt::skip]
+        let expected = Grid::from_u8s([
+                1, 2, 3, 4,
+                3, 4, 1, 2,
+                2, 3, 0, 0,
+                0, 0, 0, 0,
+            ]);
+
+        assert_eq!(solver.psg.grid, expected);
+
+        println!("{}", solver.psg);
+    }
+
+    #[test]
+    fn make_progress_on_empty_grid() {
+        let grid = Grid::empty();
+        let mut solver = grid.try_solve();
+        assert!(solver.make_progress());
+
+        println!("{}", solver.psg);
+    }
+
+    #[test]
+    fn step_through_puzzle() {
+        #[rustfmt::skip]
+        let grid = Grid::from_u8s([
+            1, 2, 3, 4,
+            3, 4, 1, 2,
+            2, 1, 4, 3,
+            0, 0, 0, 0,
+        ]);
+        let mut solver = grid.try_solve();
+
+        assert_eq!(
+            solver.step(),
+            StepOutcome::Placed(12, Digit::new(4).unwrap())
+        );
+        assert_eq!(
+            solver.step(),
+            StepOutcome::Placed(13, Digit::new(3).unwrap())
+        );
+        assert_eq!(
+            solver.step(),
+            StepOutcome::Placed(14, Digit::new(2).unwrap())
+        );
+        assert_eq!(
+            solver.step(),
+            StepOutcome::Placed(15, Digit::new(1).unwrap())
+        );
+        assert_eq!(solver.step(), StepOutcome::Solved);
+        assert_eq!(solver.grid(), &grid.assert_solvable().unwrap().grid);
+        assert_eq!(solver.step(), StepOutcome::Exhausted);
+        assert_eq!(solver.step(), StepOutcome::Exhausted);
+    }
+
+    #[test]
+    fn step_backtracks_after_solution() {
+        let grid = Grid::empty();
+        let mut solver = grid.try_solve();
+
+        for pos in 0..NB_CELL {
+            assert!(matches!(solver.step(), StepOutcome::Placed(p, _) if p == pos));
+        }
+        assert_eq!(solver.step(), StepOutcome::Solved);
+        assert_eq!(solver.step(), StepOutcome::Backtracked(NB_CELL, 9));
+
+        #[rustfmt::skip]
+        let expected = Grid::from_u8s([
+                1, 2, 3, 4,
+                3, 4, 1, 2,
+                2, 3, 0, 0,
+                0, 0, 0, 0,
+            ]);
+        assert_eq!(solver.grid(), &expected);
+    }
+
+    #[test]
+    fn reset_solver() {
+        let grid = Grid::from_hex_str("1..4.........2..").unwrap();
+        let mut solver = grid.try_solve();
+        let first = solver.next().unwrap().grid;
+        let count = 1 + solver.by_ref().count();
+        assert!(solver.next().is_none());
+
+        solver.reset();
+        assert_eq!(solver.stats(), &SolverStats::default());
+        assert_eq!(solver.next().unwrap().grid, first);
+        assert_eq!(1 + solver.count(), count);
+    }
+
+    #[test]
+    fn solve_with_trace() {
+        #[rustfmt::skip]
+        let grid = Grid::from_u8s([
+            1, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 4,
+            0, 0, 0, 0,
+        ]);
+        let (solution, trace) = grid.solve_with_trace();
+        assert_eq!(solution.unwrap().grid, grid.solve().unwrap().grid);
+
+        let d = |v| Digit::new(v).unwrap();
+        // With 2 and 3 next to the 1, the last cell of the first row can not hold the 4 of its column
+        assert_eq!(
+            trace[..5],
+            [
+                Step::Place {
+                    pos: 1,
+                    digit: d(2)
+                },
+                Step::Place {
+                    pos: 2,
+                    digit: d(3)
+                },
+                Step::Backtrack { pos: 3 },
+                Step::Try {
+                    pos: 2,
+                    digit: d(4)
+                },
+                Step::Place {
+                    pos: 3,
+                    digit: d(3)
+                },
+            ]
+        );
+        assert_eq!(trace.len(), 16);
+
+        #[rustfmt::skip]
+        let contradiction = Grid::from_u8s([
+            1, 2, 0, 0,
+            0, 0, 0, 3,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        let (solution, trace) = contradiction.solve_with_trace();
+        assert!(solution.is_none());
+        // The 3 of the block leaves no room for the 3 of the first row
+        assert_eq!(
+            trace,
+            [
+                Step::Place {
+                    pos: 2,
+                    digit: d(4)
+                },
+                Step::Backtrack { pos: 3 },
+            ]
+        );
+    }
+
+    #[test]
+    fn solver_stats() {
+        let grid = Grid::empty();
+        let mut solver = grid.try_solve();
+        assert_eq!(solver.stats(), &SolverStats::default());
+
+        // The first solution of the empty grid is found without any dead-end
+        while solver.step() != StepOutcome::Solved {}
+        assert_eq!(
+            solver.stats(),
+            &SolverStats {
+                placements: NB_CELL,
+                backtracks: 0,
+                max_depth: NB_CELL,
+            }
+        );
+
+        // Returning the solution starts the search for the next one:
+        // the last cells are emptied, and one of them filled again
+        solver.next().unwrap();
+        assert_eq!(
+            solver.stats(),
+            &SolverStats {
+                placements: 17,
+                backtracks: 6,
+                max_depth: NB_CELL,
+            }
+        );
+    }
+
+    #[test]
+    fn already_solved_grid() {
+        #[rustfmt::skip]
+        let grid = Grid::from_u8s([
+            1, 2, 3, 4,
+            3, 4, 1, 2,
+            2, 1, 4, 3,
+            4, 3, 2, 1,
+        ]);
+        let mut solver = grid.try_solve();
+        assert_eq!(solver.psg.fill_until, NB_CELL, "no search should be needed");
+        assert_eq!(solver.next().unwrap().grid, grid);
+        assert!(solver.next().is_none());
+
+        let mut solver = grid.try_solve();
+        assert_eq!(solver.step(), StepOutcome::Solved);
+        assert_eq!(solver.step(), StepOutcome::Exhausted);
+
+        // A complete grid whose digits contradict each other is not a solution
+        let mut invalid = grid.clone();
+        invalid[(3, 2)] = invalid[(3, 3)];
+        assert!(invalid.data.iter().all(Option::is_some));
+        assert!(invalid.try_solve().next().is_none());
+        assert_eq!(invalid.try_solve().step(), StepOutcome::Exhausted);
+        assert_eq!(invalid.solve(), None);
+    }
+
+    #[test]
+    fn assert_solvable() {
+        let solution = Grid::empty().assert_solvable().unwrap();
+        assert!(solution.grid.data.iter().all(|c| c.is_some()));
+
+        // The only empty cell of the first line can not be filled
+        #[rustfmt::skip]
+        let grid = Grid::from_u8s([
+            1, 2, 3, 0,
+            0, 0, 0, 4,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        assert_eq!(grid.assert_solvable().unwrap_err(), Unsolvable);
+    }
+
+    #[test]
+    fn solve_many() {
+        let grids = [
+            Grid::from_hex_str("12.4..12.1430..1").unwrap(),
+            // The third cell can hold no digit
+            Grid::from_hex_str("12....3...4.....").unwrap(),
+            Grid::empty(),
+        ];
+        let solutions = Grid::solve_many(&grids);
+        assert_eq!(solutions.len(), 3);
+        assert_eq!(solutions[0], grids[0].solve());
+        assert_eq!(solutions[1], None);
+        assert_eq!(solutions[2], Grid::empty().solve());
+        assert_eq!(Grid::solve_many(&[]), []);
+    }
+
+    #[test]
+    fn count_solutions() {
+        assert_eq!(Grid::empty().count_solutions(None), 288);
+        assert_eq!(Grid::empty().count_solutions(Some(2)), 2);
+        assert_eq!(Grid::empty().count_solutions(Some(1000)), 288);
+        assert_eq!(Grid::empty().count_solutions(Some(0)), 0);
+    }
+
+    #[test]
+    fn has_unique_solution() {
+        assert!(!Grid::empty().has_unique_solution());
+
+        #[rustfmt::skip]
+        let grid = Grid::from_u8s([
+            1, 2, 3, 4,
+            3, 4, 0, 0,
+            2, 1, 4, 3,
+            4, 3, 2, 1,
+        ]);
+        assert!(grid.has_unique_solution());
+
+        // The only empty cell of the first line can not be filled
+        #[rustfmt::skip]
+        let grid = Grid::from_u8s([
+            1, 2, 3, 0,
+            0, 0, 0, 4,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        assert!(!grid.has_unique_solution());
+    }
+
+    #[test]
+    fn is_valid() {
+        assert!(Grid::empty().is_valid());
+        let mut grid = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        assert!(grid.is_valid());
+        assert!(grid.assert_solvable().unwrap().grid().is_valid());
+
+        // Same digit as cell 0, in the same column
+        grid.data[8] = Some(Digit::new(1).unwrap());
+        assert!(!grid.is_valid());
+    }
+
+    #[test]
+    fn is_solved() {
+        assert!(!Grid::empty().is_solved());
+        let mut grid = Grid::empty().assert_solvable().unwrap().grid().clone();
+        assert!(grid.is_solved());
+
+        // Swap two digits of the first row, which is still full but has contradictions in columns
+        grid.data.swap(0, 1);
+        assert!(!grid.is_solved());
+    }
+
+    #[test]
+    fn index_by_row_and_column() {
+        let mut grid = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        assert_eq!(grid[(0, 3)], Some(Digit::new(4).unwrap()));
+        assert_eq!(grid[(1, 0)], None);
+        assert_eq!(grid[(3, 3)], Some(Digit::new(1).unwrap()));
+
+        grid[(0, 2)] = Some(Digit::new(3).unwrap());
+        assert_eq!(grid.data[2], Some(Digit::new(3).unwrap()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_the_grid() {
+        let _ = Grid::empty()[(0, NB_DIGIT)];
+    }
+
+    #[test]
+    fn empty_and_filled_cells() {
+        let grid = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        assert_eq!(grid.empty_cells().collect_vec(), [2, 4, 5, 8, 12, 13, 14]);
+        assert_eq!(
+            grid.filled_cells()
+                .map(|(pos, d)| (pos, d.value()))
+                .collect_vec(),
+            [
+                (0, 1),
+                (1, 2),
+                (3, 4),
+                (6, 1),
+                (7, 2),
+                (9, 1),
+                (10, 4),
+                (11, 3),
+                (15, 1)
+            ]
+        );
+        assert_eq!(Grid::empty().empty_cells().count(), NB_CELL);
+        assert_eq!(Grid::empty().filled_cells().count(), 0);
+    }
+
+    #[test]
+    fn default_grid() {
+        #[derive(Default)]
+        struct Game {
+            puzzle: Grid,
+            candidates: CandidateGrid,
+        }
+        let game = Game::default();
+        assert_eq!(game.puzzle, Grid::empty());
+        assert_eq!(game.candidates, Grid::empty().candidates());
+    }
+
+    #[test]
+    fn grids_in_a_hash_set() {
+        let mut grids = std::collections::HashSet::new();
+        grids.insert(Grid::from_hex_str("12.4..12.1430..1").unwrap());
+        grids.insert(Grid::from_hex_str("12.4..12.1430..1").unwrap());
+        assert_eq!(grids.len(), 1);
+        grids.insert(Grid::empty());
+        assert_eq!(grids.len(), 2);
+
+        // Every solution of the empty grid is different
+        let solutions: std::collections::HashSet<_> = Grid::empty().try_solve().collect();
+        assert_eq!(solutions.len(), Grid::empty().count_solutions(None));
+    }
+
+    #[test]
+    fn clue_count() {
+        let grid = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        assert_eq!(grid.clue_count(), 9);
+        assert_eq!(grid.empty_count(), 7);
+        assert_eq!(Grid::empty().clue_count(), 0);
+        assert_eq!(Grid::empty().empty_count(), NB_CELL);
+    }
+
+    #[test]
+    fn to_grid_2d() {
+        let grid = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        let d = |v| Digit::new(v);
+        assert_eq!(
+            grid.to_grid_2d(),
+            [
+                [d(1), d(2), None, d(4)],
+                [None, None, d(1), d(2)],
+                [None, d(1), d(4), d(3)],
+                [None, None, None, d(1)],
+            ]
+        );
+
+        let solution = grid.solve().unwrap();
+        let rows = solution.to_grid_2d();
+        assert_eq!(rows[3].map(Digit::value), [4, 3, 2, 1]);
+        assert_eq!(rows.map(|row| row.map(Some)), solution.grid().to_grid_2d());
+    }
+
+    #[test]
+    fn one_from_solved() {
+        #[rustfmt::skip]
+        let grid = Grid::from_u8s([
+            1, 2, 3, 4,
+            3, 4, 1, 2,
+            2, 1, 4, 3,
+            4, 3, 0, 1,
+        ]);
+        assert_eq!(grid.one_from_solved(), Some((14, Digit::new(2).unwrap())));
+
+        let solved = grid.assert_solvable().unwrap().grid;
+        assert_eq!(solved.one_from_solved(), None);
+
+        #[rustfmt::skip]
+        let grid = Grid::from_u8s([
+            1, 2, 3, 4,
+            3, 4, 1, 2,
+            2, 1, 4, 3,
+            4, 3, 0, 0,
+        ]);
+        assert_eq!(grid.one_from_solved(), None);
+    }
+
+    #[test]
+    fn solve_with_obstacle() {
+        #[rustfmt::skip]
+        let grid = Grid::from_u8s([
+            0, 2, 3, 4,
+            3, 4, 1, 2,
+            2, 1, 4, 3,
+            4, 3, 2, 1,
+        ]);
+        let mut obstacles = [false; NB_CELL];
+        obstacles[0] = true;
+
+        let solutions = grid.try_solve_with_obstacles(obstacles).collect_vec();
+        assert_eq!(solutions.len(), 1);
+        assert_eq!(solutions[0].grid, grid);
+
+        // Two obstacles relax enough constraints to let the bottom half be filled in more ways
+        #[rustfmt::skip]
+        let grid = Grid::from_u8s([
+            1, 2, 3, 4,
+            3, 4, 1, 2,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        let mut obstacles = [false; NB_CELL];
+        obstacles[8] = true;
+        obstacles[14] = true;
+        assert_eq!(grid.try_solve().count(), 4);
+        let solutions = grid.try_solve_with_obstacles(obstacles).collect_vec();
+        assert_eq!(solutions.len(), 8);
+        assert!(solutions
+            .iter()
+            .all(|s| s.grid.data[8].is_none() && s.grid.data[14].is_none()));
+    }
+
+    #[test]
+    fn display_with_symbols() {
+        let grid = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        let s = grid.display_with_symbols(&['A', 'B', 'C', 'D']).to_string();
+        assert_eq!(
+            s,
+            r"┌──┬──┐
+│AB│.D│
+│..│AB│
+├──┼──┤
+│.A│DC│
+│..│.A│
+└──┴──┘
+"
+        );
+    }
+
+    #[test]
+    fn ascii_display_empty_grid() {
+        assert_eq!(
+            Grid::empty().to_ascii_string(),
+            r"+--+--+
+|..|..|
+|..|..|
++--+--+
+|..|..|
+|..|..|
++--+--+
+"
+        );
+    }
+
+    #[test]
+    fn display_empty_grid() {
+        let grid = Grid::empty();
+        let s = grid.to_string();
+        assert_eq!(
+            s,
+            r"┌──┬──┐
+│..│..│
+│..│..│
+├──┼──┤
+│..│..│
+│..│..│
+└──┴──┘
+"
+        );
+    }
+}
+
+#[cfg(all(test, feature = "size-9x9"))]
+mod test_9x9 {
+    use crate::{Digit, Grid, SolutionMistake, StepOutcome};
+
+    #[test]
+    fn forward_checking_backtracks_less() {
+        let puzzle =
+            "53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79"
+                .parse::<Grid>()
+                .unwrap();
+        let mut plain = puzzle.try_solve();
+        let mut fc = puzzle.try_solve_fc();
+        while plain.step() != StepOutcome::Solved {}
+        while fc.step() != StepOutcome::Solved {}
+        assert_eq!(fc.grid(), plain.grid());
+        assert_eq!(plain.stats().backtracks, 2911);
+        assert_eq!(fc.stats().backtracks, 215);
+
+        assert_eq!(puzzle.try_solve_fc().count(), 1);
+    }
+
+    #[test]
+    fn digit_range() {
+        assert_eq!(Digit::new(0), None);
+        assert_eq!(Digit::new(10), None);
+        assert_eq!(Digit::new(9).map(Digit::value), Some(9));
+        assert_eq!(
+            Digit::all().map(Digit::value).collect::<Vec<_>>(),
+            (1..=9).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn digit_from_char() {
+        use crate::NotADigit;
+
+        assert_eq!(Digit::try_from('1'), Ok(Digit::new(1).unwrap()));
+        assert_eq!(Digit::try_from('9'), Ok(Digit::new(9).unwrap()));
+        for ch in ['0', '.', 'A', ' ', 'é'] {
+            assert_eq!(Digit::try_from(ch), Err(NotADigit(ch)));
+        }
+    }
+
+    #[test]
+    fn solve_empty_grid() {
+        let solution = Grid::empty().try_solve().next().unwrap();
+        let cells = solution.grid.data;
+        assert_eq!(
+            Grid::empty().check_solution(&cells),
+            Vec::<SolutionMistake>::new()
+        );
+    }
+
+    #[test]
+    fn solve_classic_puzzle() {
+        let puzzle =
+            "53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79"
+                .parse::<Grid>()
+                .unwrap();
+
+        let mut solver = puzzle.try_solve();
+        let solution = solver.next().unwrap();
+        #[rustfmt::skip]
+        let expected = Grid::from_u8s([
+            5, 3, 4, 6, 7, 8, 9, 1, 2,
+            6, 7, 2, 1, 9, 5, 3, 4, 8,
+            1, 9, 8, 3, 4, 2, 5, 6, 7,
+            8, 5, 9, 7, 6, 1, 4, 2, 3,
+            4, 2, 6, 8, 5, 3, 7, 9, 1,
+            7, 1, 3, 9, 2, 4, 8, 5, 6,
+            9, 6, 1, 5, 3, 7, 2, 8, 4,
+            2, 8, 7, 4, 1, 9, 6, 3, 5,
+            3, 4, 5, 2, 8, 6, 1, 7, 9,
+        ]);
+        assert_eq!(solution.grid.to_u8s(), expected.to_u8s());
+        assert!(solver.next().is_none(), "the puzzle has a unique solution");
+    }
+}
+
+#[cfg(all(test, feature = "size-16x16"))]
+mod test_16x16 {
+    use crate::{Digit, Grid, SolutionMistake, NB_CELL, NB_DIGIT};
+
+    #[test]
+    fn solve_with_full_first_row() {
+        let puzzle = Grid::from_u8s(core::array::from_fn(|pos| {
+            if pos < NB_DIGIT {
+                pos as u8 + 1
+            } else {
+                0
+            }
+        }));
+        let solution = puzzle.try_solve().next().unwrap();
+        assert_eq!(
+            puzzle.check_solution(&solution.grid.data),
+            Vec::<SolutionMistake>::new()
+        );
+        assert_eq!(
+            solution.grid.to_u8s()[..NB_DIGIT],
+            puzzle.to_u8s()[..NB_DIGIT]
+        );
+        assert!(solution.to_string().contains("│1234│5678│9ABC│DEFG│"));
+        assert_eq!(Digit::new(16).map(Digit::to_char), Some('G'));
+        assert_eq!(
+            solution.grid.to_line_string()[..NB_DIGIT],
+            *"123456789ABCDEFG"
+        );
+    }
+
+    #[test]
+    fn hex_round_trip() {
+        let solution = Grid::empty().solve().unwrap().grid;
+        let line = solution.to_line_string();
+        assert_eq!(line.chars().count(), NB_CELL);
+        assert_eq!(line.parse::<Grid>(), Ok(solution.clone()));
+        assert_eq!(line.to_lowercase().parse::<Grid>(), Ok(solution.clone()));
+
+        let mut puzzle = solution.clone();
+        puzzle[(0, 15)] = None;
+        puzzle[(15, 0)] = None;
+        let line = puzzle.to_line_string();
+        assert!(line.starts_with("123456789ABCDEF."));
+        assert_eq!(line.parse::<Grid>(), Ok(puzzle));
+    }
+
+    #[test]
+    fn digit_from_char() {
+        use crate::NotADigit;
+
+        assert_eq!(Digit::try_from('9'), Ok(Digit::new(9).unwrap()));
+        assert_eq!(Digit::try_from('A'), Ok(Digit::new(10).unwrap()));
+        assert_eq!(Digit::try_from('G'), Ok(Digit::new(16).unwrap()));
+        assert_eq!(Digit::try_from('g'), Ok(Digit::new(16).unwrap()));
+        assert_eq!(Digit::try_from('H'), Err(NotADigit('H')));
+        assert_eq!(Digit::try_from('0'), Err(NotADigit('0')));
+    }
+}
@@ -0,0 +1,170 @@
+use alloc::vec::Vec;
+
+use crate::{hint::Elimination, CandidateGrid, Digit, Grid, Technique, NB_CELL};
+
+/// A deduction of [Grid::solve_logically]
+/// Filling cells and ruling out candidates are kept apart, as only the latter leave the grid unchanged
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SolveStep {
+    /// The digit has been placed in the empty cell at [pos], found with [Technique::NakedSingle]
+    /// or [Technique::HiddenSingle]
+    /// The digit is then no longer a candidate of the peers of the cell, which is not recorded as an elimination
+    Place {
+        pos: usize,
+        digit: Digit,
+        technique: Technique,
+    },
+    /// The technique has ruled out candidates without filling any cell,
+    /// as the positions of the cells along with the digits they can no longer hold, in increasing order
+    Eliminate {
+        technique: Technique,
+        eliminations: Vec<(usize, Digit)>,
+    },
+}
+
+impl SolveStep {
+    /// The technique which led to the step
+    pub fn technique(&self) -> Technique {
+        match self {
+            SolveStep::Place { technique, .. } | SolveStep::Eliminate { technique, .. } => {
+                *technique
+            }
+        }
+    }
+}
+
+impl Grid {
+    /// Solve the grid with the techniques of [Grid::difficulty], without any guess, and record every deduction
+    /// Singles are always looked for first; when there is none, the techniques which rule out candidates are tried
+    /// from the simplest to the hardest, until one of them makes some progress
+    /// Return the grid as filled when every technique got stuck, which is complete if the puzzle is logically solvable,
+    /// along with the deductions in the order they were made
+    /// The grid itself is left untouched
+    pub fn solve_logically(&self) -> (Grid, Vec<SolveStep>) {
+        let eliminations: [(Technique, Elimination); 3] = [
+            (Technique::NakedPair, CandidateGrid::apply_naked_pairs),
+            (Technique::Pointing, CandidateGrid::apply_pointing),
+            (Technique::XWing, CandidateGrid::apply_x_wing),
+        ];
+        let mut candidates = self.candidates();
+        let mut trace = Vec::new();
+        'solve: loop {
+            if let Some((pos, digit)) = candidates.find_single() {
+                let technique = if candidates.masks[pos].count_ones() == 1 {
+                    Technique::NakedSingle
+                } else {
+                    Technique::HiddenSingle
+                };
+                candidates.place(digit, pos);
+                trace.push(SolveStep::Place {
+                    pos,
+                    digit,
+                    technique,
+                });
+                continue;
+            }
+            for (technique, apply) in eliminations {
+                let before = candidates.clone();
+                if apply(&mut candidates) == 0 {
+                    continue;
+                }
+                let eliminations = (0..NB_CELL)
+                    .flat_map(|pos| {
+                        let candidates = &candidates;
+                        before
+                            .candidates(pos)
+                            .filter(move |d| !candidates.is_candidate(*d, pos))
+                            .map(move |d| (pos, d))
+                    })
+                    .collect();
+                trace.push(SolveStep::Eliminate {
+                    technique,
+                    eliminations,
+                });
+                continue 'solve;
+            }
+            return (candidates.grid().clone(), trace);
+        }
+    }
+}
+
+// The fixtures are 9×9 grids
+#[cfg(all(test, feature = "size-9x9"))]
+mod test {
+    use crate::{Grid, SolveStep, Technique};
+
+    /// Solvable with naked singles only
+    const EASY_PUZZLE: &str =
+        "53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79";
+
+    /// Singles get stuck on this one, until naked pairs rule out some candidates
+    const PAIRS_PUZZLE: &str =
+        "...3..95....49.........8....6791..3..54......1....24..5..8.3.9..4........39.2...7";
+
+    /// Every technique gets stuck on this one from the start
+    const EXPERT_PUZZLE: &str =
+        "8..........36......7..9.2...5...7.......457.....1...3...1....68..85...1..9....4..";
+
+    #[test]
+    fn placements_fill_the_solution() {
+        let puzzle = EASY_PUZZLE.parse::<Grid>().unwrap();
+        let solution = puzzle.solve().unwrap();
+        let (grid, trace) = puzzle.solve_logically();
+
+        assert_eq!(&grid, solution.grid());
+        assert_eq!(trace.len(), puzzle.empty_cells().count());
+        for step in trace {
+            let SolveStep::Place { pos, digit, .. } = step else {
+                panic!("singles are enough, nothing to rule out: {:?}", step);
+            };
+            assert_eq!(puzzle.data[pos], None);
+            assert_eq!(solution.grid().data[pos], Some(digit));
+        }
+    }
+
+    #[test]
+    fn eliminations_are_recorded_apart_from_placements() {
+        let puzzle = PAIRS_PUZZLE.parse::<Grid>().unwrap();
+        let solution = puzzle.solve().unwrap();
+        let (grid, trace) = puzzle.solve_logically();
+        assert_eq!(&grid, solution.grid());
+
+        // Replay the trace on the candidates of the puzzle
+        let mut candidates = puzzle.candidates();
+        let mut nb_eliminations = 0;
+        for step in trace {
+            match step {
+                SolveStep::Place {
+                    pos,
+                    digit,
+                    technique,
+                } => {
+                    assert!(technique <= Technique::HiddenSingle);
+                    candidates.place(digit, pos);
+                }
+                SolveStep::Eliminate {
+                    technique,
+                    eliminations,
+                } => {
+                    nb_eliminations += 1;
+                    assert!(technique >= Technique::NakedPair);
+                    assert!(!eliminations.is_empty());
+                    assert!(eliminations.is_sorted());
+                    for (pos, d) in eliminations {
+                        // Only wrong candidates of empty cells are ruled out
+                        assert_ne!(solution.grid().data[pos], Some(d));
+                        assert!(candidates.eliminate(d, pos));
+                    }
+                }
+            }
+        }
+        assert!(nb_eliminations > 0);
+        assert_eq!(candidates.grid(), solution.grid());
+    }
+
+    #[test]
+    fn stuck_without_any_deduction() {
+        let puzzle = EXPERT_PUZZLE.parse::<Grid>().unwrap();
+        assert_eq!(puzzle.solve_logically(), (puzzle, vec![]));
+    }
+}
@@ -0,0 +1,167 @@
+use alloc::vec::Vec;
+
+use crate::{Digit, Grid, SolvedGrid, UsedDigits, NB_CELL};
+
+/// Enumerate the solutions of a grid, always guessing the empty cell with the fewest candidates
+/// This is much faster than [crate::GridSolver] on hard puzzles, but the solutions come in another order
+pub struct MrvSolver {
+    grid: Grid,
+    used: UsedDigits,
+    /// Position of every guessed cell, in the order they were guessed
+    guesses: Vec<usize>,
+    /// Set once the search space has been fully explored, so that the solver does not start over
+    exhausted: bool,
+    /// Set when the grid is complete, so that the next search starts by backtracking
+    solved: bool,
+}
+
+impl Grid {
+    /// Same as [Grid::try_solve], but with the minimum remaining values heuristic, see [MrvSolver]
+    pub fn try_solve_mrv(&self) -> MrvSolver {
+        MrvSolver {
+            grid: self.clone(),
+            used: UsedDigits::new(self),
+            guesses: Vec::new(),
+            // The givens are not checked by the search, so if they already contradict each other, there is no solution
+            exhausted: !self.is_valid(),
+            solved: false,
+        }
+    }
+}
+
+impl MrvSolver {
+    /// The empty cell with the fewest candidates, and its candidates, or None if the grid is complete
+    fn most_constrained_cell(&self) -> Option<(usize, u16)> {
+        (0..NB_CELL)
+            .filter(|pos| self.grid.data[*pos].is_none())
+            .map(|pos| (pos, self.used.candidates(pos)))
+            .min_by_key(|(_, candidates)| candidates.count_ones())
+    }
+
+    /// Place at [pos] the smallest of the [candidates] which is strictly greater than [after]
+    /// Return false if there is none
+    fn place_next(&mut self, pos: usize, candidates: u16, after: u8) -> bool {
+        let remaining = candidates.checked_shr(after.into()).unwrap_or(0);
+        if remaining == 0 {
+            return false;
+        }
+        let d = Digit::new(after + remaining.trailing_zeros() as u8 + 1)
+            .expect("candidates only hold digits");
+        self.used.add(d, pos);
+        self.grid.data[pos] = Some(d);
+        self.guesses.push(pos);
+        true
+    }
+
+    /// Replace the last guess that can be replaced by its next candidate
+    /// Return false if no guess can be replaced, meaning there is no more solution
+    fn backtrack(&mut self) -> bool {
+        while let Some(pos) = self.guesses.pop() {
+            let d = self.grid.data[pos]
+                .take()
+                .expect("a guessed cell is filled");
+            self.used.remove(d, pos);
+            if self.place_next(pos, self.used.candidates(pos), d.value()) {
+                return true;
+            }
+        }
+        self.exhausted = true;
+        false
+    }
+}
+
+impl Iterator for MrvSolver {
+    type Item = SolvedGrid;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.solved {
+            self.solved = false;
+            if !self.backtrack() {
+                return None;
+            }
+        }
+        while !self.exhausted {
+            match self.most_constrained_cell() {
+                None => {
+                    self.solved = true;
+                    return Some(SolvedGrid {
+                        grid: self.grid.clone(),
+                    });
+                }
+                Some((pos, candidates)) => {
+                    if !self.place_next(pos, candidates, 0) {
+                        // The cell can not be filled: a previous guess is wrong
+                        self.backtrack();
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Grid;
+
+    #[test]
+    #[cfg(feature = "size-4x4")]
+    fn mrv_finds_every_solution() {
+        assert_eq!(Grid::empty().try_solve_mrv().count(), 288);
+
+        let grid = Grid::from_hex_str("12..............").unwrap();
+        let mut expected = grid
+            .try_solve()
+            .map(|s| s.grid().to_u8s())
+            .collect::<Vec<_>>();
+        let mut found = grid
+            .try_solve_mrv()
+            .map(|s| s.grid().to_u8s())
+            .collect::<Vec<_>>();
+        expected.sort();
+        found.sort();
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "size-9x9")]
+    fn mrv_solves_hard_puzzle() {
+        let puzzle =
+            "8..........36......7..9.2...5...7.......457.....1...3...1....68..85...1..9....4.."
+                .parse::<Grid>()
+                .unwrap();
+        let mut solver = puzzle.try_solve_mrv();
+        assert_eq!(
+            solver.next().map(|s| s.grid().clone()),
+            puzzle.solve().map(|s| s.grid().clone())
+        );
+        assert!(solver.next().is_none());
+        assert!(solver.next().is_none());
+    }
+
+    #[test]
+    fn mrv_on_invalid_grid() {
+        // A few empty cells in the second row, and a digit twice in the first one
+        let mut grid = Grid::empty().solve().unwrap().grid().clone();
+        for column in 0..3 {
+            grid[(1, column)] = None;
+        }
+        grid[(0, 1)] = grid[(0, 0)];
+        assert_eq!(grid.try_solve_mrv().count(), grid.try_solve().count());
+        assert_eq!(grid.try_solve_mrv().count(), 0);
+
+        // Complete, with a contradiction
+        let mut grid = Grid::empty().solve().unwrap().grid().clone();
+        grid[(0, 1)] = grid[(0, 0)];
+        assert_eq!(grid.try_solve_mrv().count(), grid.try_solve().count());
+        assert_eq!(grid.try_solve_mrv().count(), 0);
+    }
+
+    #[test]
+    fn mrv_on_complete_grid() {
+        let grid = Grid::empty().solve().unwrap().grid().clone();
+        let mut solver = grid.try_solve_mrv();
+        assert_eq!(solver.next().map(|s| s.grid().clone()), Some(grid));
+        assert!(solver.next().is_none());
+    }
+}
@@ -0,0 +1,84 @@
+use rayon::prelude::*;
+
+use crate::{Digit, Grid, SolvedGrid, NB_CELL};
+
+impl Grid {
+    /// Same as [Grid::count_solutions] without limit, but with the search split over every core:
+    /// each digit that can go in the first empty cell starts a search of its own, with the sequential solver
+    pub fn count_solutions_parallel(&self) -> usize {
+        let Some(pos) = (0..NB_CELL).find(|pos| self.data[*pos].is_none()) else {
+            // A complete grid is its own solution, unless its digits contradict each other
+            return usize::from(self.is_valid());
+        };
+        Digit::all()
+            .filter(|d| self.can_accept_digit_at_pos(*d, pos))
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|d| {
+                let mut grid = self.clone();
+                grid.data[pos] = Some(d);
+                grid.count_solutions(None)
+            })
+            .sum()
+    }
+
+    /// Same as [Grid::solve_many], but the grids are spread over every core, each solved with the sequential solver
+    /// The solutions are still in the order of [grids]
+    pub fn solve_many_parallel(grids: &[Grid]) -> Vec<Option<SolvedGrid>> {
+        grids.par_iter().map(Grid::solve).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Grid;
+
+    #[test]
+    #[cfg(feature = "size-4x4")]
+    fn same_count_as_sequential() {
+        for puzzle in [
+            "................",
+            "1..4.........2..",
+            "12.....3........",
+            "1.3..4.22...4..1",
+        ] {
+            let grid = Grid::from_hex_str(puzzle).unwrap();
+            assert_eq!(grid.count_solutions_parallel(), grid.count_solutions(None));
+        }
+        assert_eq!(Grid::empty().count_solutions_parallel(), 288);
+
+        // A complete grid, solved or holding a contradiction
+        let mut complete = Grid::from_hex_str("1234341221434321").unwrap();
+        assert_eq!(complete.count_solutions_parallel(), 1);
+        complete[(3, 2)] = complete[(3, 3)];
+        assert_eq!(complete.count_solutions(None), 0);
+        assert_eq!(complete.count_solutions_parallel(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "size-4x4")]
+    fn solve_many_in_order() {
+        let grids = Grid::empty()
+            .try_solve()
+            .map(|solution| {
+                let mut puzzle = solution.grid().clone();
+                puzzle.data[..8].fill(None);
+                puzzle
+            })
+            .chain([Grid::from_hex_str("12....3...4.....").unwrap()])
+            .collect::<Vec<_>>();
+        assert_eq!(Grid::solve_many_parallel(&grids), Grid::solve_many(&grids));
+    }
+
+    #[test]
+    #[cfg(feature = "size-9x9")]
+    fn same_count_as_sequential() {
+        // An easy puzzle, without the givens of its last two rows
+        let grid =
+            "53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28..................."
+                .parse::<Grid>()
+                .unwrap();
+        assert_eq!(grid.count_solutions(None), 240);
+        assert_eq!(grid.count_solutions_parallel(), 240);
+    }
+}
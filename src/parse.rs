@@ -0,0 +1,696 @@
+use alloc::{string::String, vec::Vec};
+use core::str::FromStr;
+
+use crate::{cell_index, Cell, Digit, Grid, NB_CELL, NB_DIGIT};
+
+/// Why a text could not be turned into a [Grid]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The text does not hold exactly one character per cell
+    WrongLength { expected: usize, got: usize },
+    /// The character at this index is neither a blank nor a digit of this grid size
+    BadChar { index: usize, ch: char },
+    /// There is not exactly one row per line of the grid, see [Grid::from_rows]
+    WrongRowCount { expected: usize, got: usize },
+    /// This row does not have exactly one character per column, see [Grid::from_rows]
+    WrongRowLength {
+        row: usize,
+        expected: usize,
+        got: usize,
+    },
+    /// The digit at this index contradicts a digit placed before it
+    Contradiction { index: usize, digit: Digit },
+}
+
+/// Why nested rows of numbers could not be turned into a [Grid], see [Grid::from_nested]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromNestedError {
+    /// There is not exactly one row per line of the grid
+    WrongRowCount { expected: usize, got: usize },
+    /// This row does not have exactly one number per column
+    WrongRowLength {
+        row: usize,
+        expected: usize,
+        got: usize,
+    },
+    /// The number is neither 0 (empty) nor a digit of this grid size
+    OutOfRange {
+        row: usize,
+        column: usize,
+        value: u8,
+    },
+    /// The digit contradicts a digit placed before it
+    Contradiction {
+        row: usize,
+        column: usize,
+        digit: Digit,
+    },
+}
+
+/// Why an array of numbers could not be turned into a [Grid], see [Grid::try_from_u8s]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// The number is neither 0 (empty) nor a digit of this grid size
+    OutOfRange { index: usize, value: u8 },
+    /// The digit contradicts a digit placed before it
+    Contradiction { index: usize, digit: Digit },
+}
+
+/// Value of a character in the hexadecimal-like alphabet used by large grids, see [Digit::try_from]
+/// `.` and `0` are blanks, and have value 0
+fn hex_char_value(ch: char) -> Option<u8> {
+    match ch {
+        '.' | '0' => Some(0),
+        _ => Digit::try_from(ch).ok().map(Digit::value),
+    }
+}
+
+impl Grid {
+    /// Parse a grid written on a single line, one character per cell, in row-major order
+    /// Digits above 9 are written `A`, `B`, ... so that any size up to 16×16 fits one character per cell
+    /// `.` and `0` stand for empty cells
+    pub fn from_hex_str(s: &str) -> Result<Grid, ParseError> {
+        grid_without_contradiction(cells_from_str(s, hex_char_value)?)
+    }
+
+    /// Write the grid in the single line format read by [Grid::from_hex_str], with `.` for empty cells
+    pub fn to_line_string(&self) -> String {
+        self.to_line_bytes().into_iter().map(char::from).collect()
+    }
+
+    /// Same as [Grid::to_line_string], as ASCII bytes in a fixed-size array, so without any allocation
+    pub fn to_line_bytes(&self) -> [u8; NB_CELL] {
+        self.data
+            .map(|cell| cell.map_or(b'.', |d| d.to_char() as u8))
+    }
+
+    /// Parse a single line grid written with [symbols] instead of decimal digits, as shown by
+    /// [Grid::display_with_symbols]: the first symbol stands for the digit 1, and so on
+    /// `.` stands for empty cells
+    pub fn from_symbols_str(s: &str, symbols: &[char; NB_DIGIT]) -> Result<Grid, ParseError> {
+        let symbol_value = |ch: char| match ch {
+            '.' => Some(0),
+            _ => symbols.iter().position(|s| *s == ch).map(|i| i as u8 + 1),
+        };
+        grid_without_contradiction(cells_from_str(s, symbol_value)?)
+    }
+
+    /// Parse a grid written as one string per row, like hand-written fixtures
+    /// Each row holds one character per column, `.`, `0` and space standing for empty cells,
+    /// and may end with a single newline
+    /// The index of [ParseError::BadChar] and [ParseError::Contradiction] is the index of the cell
+    pub fn from_rows(rows: &[&str]) -> Result<Grid, ParseError> {
+        grid_without_contradiction(rows_cells(rows)?)
+    }
+
+    /// Build a grid from one vector of numbers per row, 0 standing for an empty cell
+    /// Meant for data built dynamically, which may not be rectangular
+    pub fn from_nested(rows: &[Vec<u8>]) -> Result<Grid, FromNestedError> {
+        if rows.len() != NB_DIGIT {
+            return Err(FromNestedError::WrongRowCount {
+                expected: NB_DIGIT,
+                got: rows.len(),
+            });
+        }
+        if let Some((row, cells)) = rows.iter().enumerate().find(|(_, r)| r.len() != NB_DIGIT) {
+            return Err(FromNestedError::WrongRowLength {
+                row,
+                expected: NB_DIGIT,
+                got: cells.len(),
+            });
+        }
+
+        let mut grid = Grid::empty();
+        for (row, cells) in rows.iter().enumerate() {
+            for (column, &value) in cells.iter().enumerate() {
+                if value == 0 {
+                    continue;
+                }
+                let digit =
+                    Digit::new(value).ok_or(FromNestedError::OutOfRange { row, column, value })?;
+                if !grid.can_accept_digit_at_pos(digit, cell_index(row, column)) {
+                    return Err(FromNestedError::Contradiction { row, column, digit });
+                }
+                grid[(row, column)] = Some(digit);
+            }
+        }
+        Ok(grid)
+    }
+
+    /// Build a grid from one number per cell, in row-major order, 0 standing for an empty cell
+    pub fn try_from_u8s(array: [u8; NB_CELL]) -> Result<Grid, GridError> {
+        let mut grid = Grid::empty();
+        for (index, value) in array.into_iter().enumerate() {
+            if value == 0 {
+                continue;
+            }
+            let digit = Digit::new(value).ok_or(GridError::OutOfRange { index, value })?;
+            if !grid.can_accept_digit_at_pos(digit, index) {
+                return Err(GridError::Contradiction { index, digit });
+            }
+            grid.data[index] = Some(digit);
+        }
+        Ok(grid)
+    }
+
+    /// Parse a grid in any of the supported text formats, recognized from the content:
+    /// - The `.ss` format if a row holds `|` or if there are divider lines, read by [Grid::from_ss]
+    /// - The `.sdk` format if there are comment lines starting with `#`, read by [Grid::from_sdk]
+    /// - A single line, read by [Grid::from_hex_str]
+    /// - Otherwise one row per line, read by [Grid::from_rows]
+    ///
+    /// Blank lines are ignored, and the errors are those of the parser of the recognized format
+    pub fn parse_auto(s: &str) -> Result<Grid, ParseError> {
+        match Format::detect(s) {
+            Format::Ss => Grid::from_ss(s),
+            Format::Sdk => Grid::from_sdk(s),
+            Format::SingleLine => Grid::from_hex_str(s.trim()),
+            Format::Rows => Grid::from_rows(&non_blank_lines(s)),
+        }
+    }
+
+    /// Parse a grid in the `.sdk` format of SadMan Software: one row per line, `.` and `0` for blanks,
+    /// after optional comment lines starting with `#`
+    /// Unlike [Grid::parse_auto], each row must be a whole line, so that a missing or extra cell is reported
+    /// as [ParseError::WrongRowLength], where rows are counted without comments and blank lines
+    pub fn from_sdk(text: &str) -> Result<Grid, ParseError> {
+        Grid::from_rows(&sdk_rows(text))
+    }
+
+    /// Parse a grid in the `.ss` format of Simple Sudoku: one row per line, `.` for blanks, where the blocks
+    /// of a row are separated by `|`, and the bands of rows by divider lines like `---+---+---`
+    /// As with [Grid::from_sdk], a missing or extra cell is reported as [ParseError::WrongRowLength],
+    /// where rows are counted without divider lines and blank lines
+    pub fn from_ss(text: &str) -> Result<Grid, ParseError> {
+        let rows = ss_rows(text);
+        Grid::from_rows(&rows.iter().map(String::as_str).collect::<Vec<_>>())
+    }
+
+    /// Parse a collection of puzzles, one per line in the format of [Grid::from_hex_str],
+    /// skipping blank lines and comment lines starting with `#`
+    /// Each puzzle comes with its line number, starting at 1, so that a bad puzzle can be reported
+    /// without failing the whole collection
+    pub fn parse_many(text: &str) -> Vec<(usize, Result<Grid, ParseError>)> {
+        (1..)
+            .zip(text.lines().map(str::trim))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .map(|(number, line)| (number, Grid::from_hex_str(line)))
+            .collect()
+    }
+}
+
+/// The single line format, as read by [Grid::from_hex_str]: `"53..7....6..195..."` for a 9×9 grid
+impl FromStr for Grid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::from_hex_str(s)
+    }
+}
+
+/// One number per cell, in row-major order, 0 standing for an empty cell, see [Grid::try_from_u8s]
+impl TryFrom<[u8; NB_CELL]> for Grid {
+    type Error = GridError;
+
+    fn try_from(array: [u8; NB_CELL]) -> Result<Self, Self::Error> {
+        Grid::try_from_u8s(array)
+    }
+}
+
+/// Read the cells of a grid in any format accepted by [Grid::parse_auto], without checking that digits
+/// do not contradict each other
+/// Meant for grids which must be checked rather than trusted, like a claimed solution
+pub fn parse_cells(s: &str) -> Result<[Cell; NB_CELL], ParseError> {
+    match Format::detect(s) {
+        Format::Ss => rows_cells(&ss_rows(s).iter().map(String::as_str).collect::<Vec<_>>()),
+        Format::Sdk => rows_cells(&sdk_rows(s)),
+        Format::SingleLine => cells_from_str(s.trim(), hex_char_value),
+        Format::Rows => rows_cells(&non_blank_lines(s)),
+    }
+}
+
+/// The text formats recognized by [Grid::parse_auto]
+enum Format {
+    Ss,
+    Sdk,
+    SingleLine,
+    Rows,
+}
+
+impl Format {
+    /// Recognize the format of [s] from its dividers, its comments and its number of lines, see [Grid::parse_auto]
+    fn detect(s: &str) -> Format {
+        let lines = non_blank_lines(s);
+        let trimmed = || lines.iter().map(|line| line.trim());
+        if trimmed().any(|line| line.contains('|') || is_ss_divider(line)) {
+            Format::Ss
+        } else if trimmed().any(|line| line.starts_with('#')) {
+            Format::Sdk
+        } else if lines.len() <= 1 {
+            Format::SingleLine
+        } else {
+            Format::Rows
+        }
+    }
+}
+
+/// The lines of [s] which hold more than whitespace
+fn non_blank_lines(s: &str) -> Vec<&str> {
+    s.lines().filter(|line| !line.trim().is_empty()).collect()
+}
+
+/// The rows of a grid in the `.sdk` format, without comments and blank lines, see [Grid::from_sdk]
+fn sdk_rows(text: &str) -> Vec<&str> {
+    text.lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect()
+}
+
+/// The rows of a grid in the `.ss` format, without dividers and blank lines, see [Grid::from_ss]
+fn ss_rows(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !is_ss_divider(line))
+        .map(|line| line.chars().filter(|c| *c != '|').collect())
+        .collect()
+}
+
+/// Whether the line separates two bands of rows in the `.ss` format, like `---+---+---`
+fn is_ss_divider(line: &str) -> bool {
+    line.chars().all(|c| matches!(c, '-' | '+' | '|'))
+}
+
+/// Build a grid from parsed cells, checking that no digit contradicts a digit placed before it
+fn grid_without_contradiction(cells: [Cell; NB_CELL]) -> Result<Grid, ParseError> {
+    let mut grid = Grid::empty();
+    for (index, cell) in cells.into_iter().enumerate() {
+        let Some(digit) = cell else {
+            continue;
+        };
+        if !grid.can_accept_digit_at_pos(digit, index) {
+            return Err(ParseError::Contradiction { index, digit });
+        }
+        grid.data[index] = Some(digit);
+    }
+    Ok(grid)
+}
+
+/// Read the cells of a grid written as one string per row, see [Grid::from_rows]
+fn rows_cells(rows: &[&str]) -> Result<[Cell; NB_CELL], ParseError> {
+    if rows.len() != NB_DIGIT {
+        return Err(ParseError::WrongRowCount {
+            expected: NB_DIGIT,
+            got: rows.len(),
+        });
+    }
+    let rows = rows.iter().map(|row| row.strip_suffix('\n').unwrap_or(row));
+    let mut line = String::with_capacity(NB_CELL);
+    for (row, cells) in rows.enumerate() {
+        let got = cells.chars().count();
+        if got != NB_DIGIT {
+            return Err(ParseError::WrongRowLength {
+                row,
+                expected: NB_DIGIT,
+                got,
+            });
+        }
+        line.push_str(cells);
+    }
+
+    let char_value = |ch: char| match ch {
+        ' ' => Some(0),
+        _ => hex_char_value(ch),
+    };
+    cells_from_str(&line, char_value)
+}
+
+/// Read the cells of a single line grid, without checking for contradictions
+/// [char_value] gives the value of a character, 0 for a blank, like [hex_char_value]
+fn cells_from_str(
+    s: &str,
+    char_value: impl Fn(char) -> Option<u8>,
+) -> Result<[Cell; NB_CELL], ParseError> {
+    let got = s.chars().count();
+    if got != NB_CELL {
+        return Err(ParseError::WrongLength {
+            expected: NB_CELL,
+            got,
+        });
+    }
+
+    let mut cells = [None; NB_CELL];
+    for ((index, ch), cell) in s.chars().enumerate().zip(cells.iter_mut()) {
+        let value = char_value(ch)
+            .filter(|v| usize::from(*v) <= NB_DIGIT)
+            .ok_or(ParseError::BadChar { index, ch })?;
+        // 0 is the only value left which is not a digit, and stands for an empty cell
+        *cell = Digit::new(value);
+    }
+    Ok(cells)
+}
+
+// The fixtures are 4×4 grids
+#[cfg(all(test, feature = "size-4x4"))]
+mod test {
+    use crate::{parse_cells, Digit, FromNestedError, Grid, GridError, ParseError, NB_CELL};
+
+    #[test]
+    fn parse_hex_str() {
+        let grid = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+
+        #[rustfmt::skip]
+        let expected = Grid::from_u8s([
+            1, 2, 0, 4,
+            0, 0, 1, 2,
+            0, 1, 4, 3,
+            0, 0, 0, 1,
+        ]);
+        assert_eq!(grid, expected);
+        grid.assert_solvable().unwrap();
+
+        assert_eq!("12.4..12.1430..1".parse::<Grid>(), Ok(expected));
+        assert_eq!(grid.to_line_string(), "12.4..12.1430..1".replace('0', "."));
+        assert_eq!(&grid.to_line_bytes(), b"12.4..12.143...1");
+        assert_eq!(grid.to_line_string().parse::<Grid>(), Ok(grid));
+        assert_eq!(
+            "12.4".parse::<Grid>(),
+            Err(ParseError::WrongLength {
+                expected: NB_CELL,
+                got: 4
+            })
+        );
+    }
+
+    #[test]
+    fn parse_hex_str_errors() {
+        assert_eq!(
+            Grid::from_hex_str("1234"),
+            Err(ParseError::WrongLength {
+                expected: NB_CELL,
+                got: 4
+            })
+        );
+        // 5 is not a digit of a 4×4 grid
+        assert_eq!(
+            Grid::from_hex_str("1234341221434325"),
+            Err(ParseError::BadChar { index: 15, ch: '5' })
+        );
+        assert_eq!(
+            Grid::from_hex_str("12x4341221434321"),
+            Err(ParseError::BadChar { index: 2, ch: 'x' })
+        );
+        assert_eq!(
+            Grid::from_hex_str("1214341221434321"),
+            Err(ParseError::Contradiction {
+                index: 2,
+                digit: Digit::new(1).unwrap()
+            })
+        );
+    }
+
+    #[test]
+    fn try_from_u8s() {
+        #[rustfmt::skip]
+        let array = [
+            1, 2, 0, 4,
+            0, 0, 1, 2,
+            0, 1, 4, 3,
+            0, 0, 0, 1,
+        ];
+        assert_eq!(Grid::try_from_u8s(array), Ok(Grid::from_u8s(array)));
+        assert_eq!(Grid::try_from(array), Ok(Grid::from_u8s(array)));
+
+        let mut out_of_range = array;
+        out_of_range[2] = 5;
+        assert_eq!(
+            Grid::try_from_u8s(out_of_range),
+            Err(GridError::OutOfRange { index: 2, value: 5 })
+        );
+        assert_eq!(
+            Grid::try_from(out_of_range),
+            Err(GridError::OutOfRange { index: 2, value: 5 })
+        );
+
+        let mut contradiction = array;
+        contradiction[4] = 2;
+        assert_eq!(
+            Grid::try_from_u8s(contradiction),
+            Err(GridError::Contradiction {
+                index: 4,
+                digit: Digit::new(2).unwrap()
+            })
+        );
+    }
+
+    #[test]
+    fn parse_auto_single_line() {
+        let expected = Grid::from_hex_str("12.4..12.1430..1");
+        assert_eq!(Grid::parse_auto("\n 12.4..12.1430..1\n"), expected);
+        assert_eq!(
+            Grid::parse_auto("12.4..12.143"),
+            Err(ParseError::WrongLength {
+                expected: 16,
+                got: 12
+            })
+        );
+    }
+
+    #[test]
+    fn parse_auto_rows() {
+        let expected = Grid::from_hex_str("12.4..12.1430..1");
+        assert_eq!(Grid::parse_auto("12.4\n  12\n\n.143\r\n0..1\n"), expected);
+        assert_eq!(
+            Grid::parse_auto("12.4\n..12\n.143\n"),
+            Err(ParseError::WrongRowCount {
+                expected: 4,
+                got: 3
+            })
+        );
+    }
+
+    #[test]
+    fn parse_auto_sdk() {
+        let expected = Grid::from_hex_str("12.4..12.1430..1");
+        assert_eq!(
+            Grid::parse_auto("#A puzzle\n#Author\n12.4\n..12\n\n.143\n...1\n"),
+            expected
+        );
+        // A comment is not a row
+        assert_eq!(
+            Grid::parse_auto("#A puzzle\n12.4\n..12\n.143\n"),
+            Err(ParseError::WrongRowCount {
+                expected: 4,
+                got: 3
+            })
+        );
+    }
+
+    #[test]
+    fn parse_auto_ss() {
+        let expected = Grid::from_hex_str("12.4..12.1430..1");
+        assert_eq!(
+            Grid::parse_auto("12|.4\n..|12\n-----\n.1|43\n..|.1\n"),
+            expected
+        );
+        // Dividers alone are enough to recognize the format
+        assert_eq!(
+            Grid::parse_auto("12.4\n..12\n--+--\n.143\n...1\n"),
+            expected
+        );
+        assert_eq!(
+            Grid::parse_auto("12|.4\n..|12\n-----\n.1|43\n..|.\n"),
+            Err(ParseError::WrongRowLength {
+                row: 3,
+                expected: 4,
+                got: 3
+            })
+        );
+    }
+
+    #[test]
+    fn from_ss() {
+        assert_eq!(
+            Grid::from_ss("12|.4\r\n..|12\r\n--+--\r\n.1|43\r\n..|.1\r\n"),
+            Grid::from_hex_str("12.4..12.1430..1")
+        );
+        assert_eq!(
+            Grid::from_ss("12|.4\n..|12\n--+--\n.1|43\n"),
+            Err(ParseError::WrongRowCount {
+                expected: 4,
+                got: 3
+            })
+        );
+    }
+
+    #[test]
+    fn parse_cells_of_each_format() {
+        // Contradictions are kept
+        let cells = parse_cells("11.4..12.1430..1").unwrap();
+        assert_eq!(parse_cells("11.4\n..12\n.143\n0..1\n"), Ok(cells));
+        assert_eq!(
+            parse_cells("#A puzzle\n11.4\n..12\n.143\n0..1\n"),
+            Ok(cells)
+        );
+        assert_eq!(
+            parse_cells("11|.4\n..|12\n--+--\n.1|43\n0.|.1\n"),
+            Ok(cells)
+        );
+    }
+
+    #[test]
+    fn from_sdk() {
+        let expected = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        assert_eq!(
+            Grid::from_sdk("#A A puzzle\r\n#D Easy\r\n12.4\r\n..12\r\n\r\n.143\r\n0..1\r\n"),
+            Ok(expected.clone())
+        );
+        assert_eq!(Grid::from_sdk("12.4\n..12\n.143\n0..1"), Ok(expected));
+
+        assert_eq!(
+            Grid::from_sdk("#A puzzle\n12.4\n..12\n.143\n"),
+            Err(ParseError::WrongRowCount {
+                expected: 4,
+                got: 3
+            })
+        );
+        // Each row must be a whole line
+        assert_eq!(
+            Grid::from_sdk("12.4.\n.12\n.143\n0..1\n"),
+            Err(ParseError::WrongRowLength {
+                row: 0,
+                expected: 4,
+                got: 5
+            })
+        );
+    }
+
+    #[test]
+    fn parse_many() {
+        let text =
+            "# Two puzzles and a typo\n12.4..12.1430..1\n\n  2...............\r\n12.4..12.143\n";
+        let puzzles = Grid::parse_many(text);
+        assert_eq!(
+            puzzles,
+            [
+                (2, Grid::from_hex_str("12.4..12.1430..1")),
+                (4, Grid::from_hex_str("2...............")),
+                (
+                    5,
+                    Err(ParseError::WrongLength {
+                        expected: 16,
+                        got: 12
+                    })
+                ),
+            ]
+        );
+        assert_eq!(Grid::parse_many("\n# Nothing\n"), []);
+    }
+
+    #[test]
+    fn from_rows() {
+        assert_eq!(
+            Grid::from_rows(&["12.4", "  12\n", "0143", "...1\n"]),
+            Ok(Grid::from_hex_str("12.4..12.1430..1").unwrap())
+        );
+    }
+
+    #[test]
+    fn from_rows_errors() {
+        assert_eq!(
+            Grid::from_rows(&["1234"]),
+            Err(ParseError::WrongRowCount {
+                expected: 4,
+                got: 1
+            })
+        );
+        assert_eq!(
+            Grid::from_rows(&["....", "....\n\n", "....", "...."]),
+            Err(ParseError::WrongRowLength {
+                row: 1,
+                expected: 4,
+                got: 5
+            })
+        );
+        assert_eq!(
+            Grid::from_rows(&["....", "....", ".x..", "...."]),
+            Err(ParseError::BadChar { index: 9, ch: 'x' })
+        );
+        assert_eq!(
+            Grid::from_rows(&["...3", "....", "....", "...3"]),
+            Err(ParseError::Contradiction {
+                index: 15,
+                digit: Digit::new(3).unwrap()
+            })
+        );
+    }
+
+    #[test]
+    fn from_nested() {
+        let rows = vec![
+            vec![1, 2, 0, 4],
+            vec![0, 0, 1, 2],
+            vec![0, 1, 4, 3],
+            vec![0, 0, 0, 1],
+        ];
+        assert_eq!(
+            Grid::from_nested(&rows),
+            Ok(Grid::from_hex_str("12.4..12.1430..1").unwrap())
+        );
+    }
+
+    #[test]
+    fn from_nested_errors() {
+        assert_eq!(
+            Grid::from_nested(&[vec![1, 2, 3, 4]]),
+            Err(FromNestedError::WrongRowCount {
+                expected: 4,
+                got: 1
+            })
+        );
+        assert_eq!(
+            Grid::from_nested(&[vec![0; 4], vec![0; 4], vec![0; 3], vec![0; 5]]),
+            Err(FromNestedError::WrongRowLength {
+                row: 2,
+                expected: 4,
+                got: 3
+            })
+        );
+        assert_eq!(
+            Grid::from_nested(&[vec![0; 4], vec![0, 5, 0, 0], vec![0; 4], vec![0; 4]]),
+            Err(FromNestedError::OutOfRange {
+                row: 1,
+                column: 1,
+                value: 5
+            })
+        );
+        assert_eq!(
+            Grid::from_nested(&[vec![0; 4], vec![0, 3, 0, 0], vec![0; 4], vec![0, 3, 0, 0]]),
+            Err(FromNestedError::Contradiction {
+                row: 3,
+                column: 1,
+                digit: Digit::new(3).unwrap()
+            })
+        );
+    }
+
+    #[test]
+    fn parse_symbols_str() {
+        let symbols = ['🍎', '🍐', '🍋', '🍇'];
+        let grid = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        let s = "🍎🍐.🍇..🍎🍐.🍎🍇🍋...🍎";
+        assert_eq!(Grid::from_symbols_str(s, &symbols), Ok(grid));
+
+        assert_eq!(
+            Grid::from_symbols_str("🍎🍐.🍇..🍎🍐.🍎🍇🍋..1🍎", &symbols),
+            Err(ParseError::BadChar { index: 14, ch: '1' })
+        );
+        assert_eq!(
+            Grid::from_symbols_str("🍎🍎..............", &symbols),
+            Err(ParseError::Contradiction {
+                index: 1,
+                digit: Digit::new(1).unwrap()
+            })
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{BLOCK_SIDE, NB_CELL, NB_DIGIT};
+
+/// Number of cells sharing a row, a column or a block with a given cell: 20 for a 9×9 grid
+pub const NB_PEERS: usize = 2 * (NB_DIGIT - 1) + (BLOCK_SIDE - 1) * (BLOCK_SIDE - 1);
+
+/// Computed once, at compile time
+static PEERS: [[usize; NB_PEERS]; NB_CELL] = compute_peers();
+
+/// Every cell, other than [pos] itself, that shares a row, a column or a block with [pos], in increasing order
+pub fn peers(pos: usize) -> &'static [usize; NB_PEERS] {
+    &PEERS[pos]
+}
+
+const fn is_peer(pos: usize, other: usize) -> bool {
+    let (row, column) = (pos / NB_DIGIT, pos % NB_DIGIT);
+    let (other_row, other_column) = (other / NB_DIGIT, other % NB_DIGIT);
+    let same_block = row / BLOCK_SIDE == other_row / BLOCK_SIDE
+        && column / BLOCK_SIDE == other_column / BLOCK_SIDE;
+    pos != other && (row == other_row || column == other_column || same_block)
+}
+
+// Iterators can not be used in a const fn
+const fn compute_peers() -> [[usize; NB_PEERS]; NB_CELL] {
+    let mut peers = [[0; NB_PEERS]; NB_CELL];
+    let mut pos = 0;
+    while pos < NB_CELL {
+        let mut count = 0;
+        let mut other = 0;
+        while other < NB_CELL {
+            if is_peer(pos, other) {
+                peers[pos][count] = other;
+                count += 1;
+            }
+            other += 1;
+        }
+        assert!(count == NB_PEERS);
+        pos += 1;
+    }
+    peers
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::{peers, NB_CELL, NB_PEERS};
+
+    #[test]
+    fn peers_are_unique() {
+        for pos in 0..NB_CELL {
+            let unique = peers(pos).iter().collect::<HashSet<_>>();
+            assert_eq!(unique.len(), NB_PEERS);
+            assert!(!unique.contains(&pos));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "size-9x9")]
+    fn center_cell_has_20_peers() {
+        assert_eq!(NB_PEERS, 20);
+        assert_eq!(
+            peers(40).to_vec(),
+            vec![4, 13, 22, 30, 31, 32, 36, 37, 38, 39, 41, 42, 43, 44, 48, 49, 50, 58, 67, 76]
+        );
+    }
+}
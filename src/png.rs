@@ -0,0 +1,154 @@
+use std::io::Cursor;
+
+use image::{GrayImage, ImageFormat, Luma};
+
+use crate::{cell_index, Digit, Grid, BLOCK_SIDE, NB_DIGIT};
+
+const GLYPH_WIDTH: u32 = 5;
+const GLYPH_HEIGHT: u32 = 7;
+
+/// A 5×7 pixel glyph per digit, `1` to `9` then `A` to `G` like [Digit::to_char], one row of 5 bits per line
+/// Built in, so that no font file is needed
+#[rustfmt::skip]
+const GLYPHS: [[u8; GLYPH_HEIGHT as usize]; 16] = [
+    [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110],
+    [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111],
+    [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110],
+    [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010],
+    [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110],
+    [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110],
+    [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000],
+    [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110],
+    [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100],
+    [0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001],
+    [0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110],
+    [0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110],
+    [0b11100, 0b10010, 0b10001, 0b10001, 0b10001, 0b10010, 0b11100],
+    [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111],
+    [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000],
+    [0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111],
+];
+
+const BLACK: Luma<u8> = Luma([0]);
+const WHITE: Luma<u8> = Luma([255]);
+
+impl Grid {
+    /// A PNG image of the grid, in gray levels, where each cell is a square of [cell_px] pixels
+    /// The lines between cells are thicker on the borders of the blocks and of the grid,
+    /// and each digit is centered in its cell, drawn with a built-in pixel font, see [Grid::to_svg] for vector graphics
+    /// Cells smaller than 9 pixels are too small for the font: the digits are left out
+    /// PANIC if [cell_px] is 0
+    pub fn render_png(&self, cell_px: u32) -> Vec<u8> {
+        assert!(cell_px > 0, "a cell must be at least one pixel wide");
+        let thin = 1 + cell_px / 40;
+        let thick = 2 * thin;
+        // The outer border is drawn around the cells, not over them
+        let margin = thick / 2;
+        let size = cell_px * NB_DIGIT as u32 + thick;
+        let mut image = GrayImage::from_pixel(size, size, WHITE);
+
+        for line in 0..=NB_DIGIT {
+            let width = if line % BLOCK_SIDE == 0 { thick } else { thin };
+            let start = margin + line as u32 * cell_px - width / 2;
+            for offset in start..start + width {
+                for along in 0..size {
+                    image.put_pixel(offset, along, BLACK);
+                    image.put_pixel(along, offset, BLACK);
+                }
+            }
+        }
+
+        // The digits take about 60% of the height of a cell, and do not fit in tiny cells
+        let scale = (cell_px * 6 / 10 / GLYPH_HEIGHT).max(1);
+        let digits_fit = GLYPH_HEIGHT * scale + thick <= cell_px;
+        for row in (0..NB_DIGIT).filter(|_| digits_fit) {
+            for column in 0..NB_DIGIT {
+                let Some(d) = self.data[cell_index(row, column)] else {
+                    continue;
+                };
+                let center_x = margin + column as u32 * cell_px + cell_px / 2;
+                let center_y = margin + row as u32 * cell_px + cell_px / 2;
+                draw_glyph(
+                    &mut image,
+                    d,
+                    scale,
+                    center_x - GLYPH_WIDTH * scale / 2,
+                    center_y - GLYPH_HEIGHT * scale / 2,
+                );
+            }
+        }
+
+        let mut png = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .expect("encoding a PNG in memory can not fail");
+        png
+    }
+}
+
+/// Draw the glyph of [d], each of its pixels becoming a square of [scale] pixels, from [left] and [top]
+fn draw_glyph(image: &mut GrayImage, d: Digit, scale: u32, left: u32, top: u32) {
+    let glyph = &GLYPHS[usize::from(d.value()) - 1];
+    for (y, bits) in (0..).zip(glyph) {
+        for x in (0..GLYPH_WIDTH).filter(|x| bits & (1 << (GLYPH_WIDTH - 1 - x)) != 0) {
+            for dy in 0..scale {
+                for dx in 0..scale {
+                    image.put_pixel(left + x * scale + dx, top + y * scale + dy, BLACK);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use image::{GrayImage, ImageFormat};
+
+    use crate::{Grid, NB_DIGIT};
+
+    fn decode(png: &[u8]) -> GrayImage {
+        image::load_from_memory_with_format(png, ImageFormat::Png)
+            .unwrap()
+            .into_luma8()
+    }
+
+    /// Whether the inside of the cell at [row] and [column], away from the lines, has any black pixel
+    fn cell_is_inked(image: &GrayImage, cell_px: u32, row: u32, column: u32) -> bool {
+        let margin = 1 + cell_px / 40;
+        let inside = margin + 1..cell_px.saturating_sub(margin + 1);
+        inside.clone().any(|y| {
+            inside.clone().any(|x| {
+                image.get_pixel(margin + column * cell_px + x, margin + row * cell_px + y)[0] == 0
+            })
+        })
+    }
+
+    #[test]
+    fn render_png() {
+        let solution = Grid::empty().solve().unwrap();
+        let mut grid = solution.grid().clone();
+        grid.data[1] = None;
+
+        let image = decode(&grid.render_png(30));
+        let size = 30 * NB_DIGIT as u32 + 2;
+        assert_eq!(image.dimensions(), (size, size));
+        // The outer border
+        assert_eq!(image.get_pixel(0, size / 2)[0], 0);
+        assert_eq!(image.get_pixel(size - 1, size / 2)[0], 0);
+        // Inside the first cell, away from the digit
+        assert_eq!(image.get_pixel(4, 4)[0], 255);
+
+        assert!(cell_is_inked(&image, 30, 0, 0));
+        assert!(!cell_is_inked(&image, 30, 0, 1));
+    }
+
+    #[test]
+    fn render_png_at_any_resolution() {
+        let grid = Grid::empty().solve().unwrap().grid().clone();
+        for cell_px in [1, 8, 9, 100] {
+            let image = decode(&grid.render_png(cell_px));
+            assert_eq!(image.width(), image.height());
+            assert_eq!(cell_is_inked(&image, cell_px, 0, 0), cell_px >= 9);
+        }
+    }
+}
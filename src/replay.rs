@@ -0,0 +1,155 @@
+use alloc::vec::Vec;
+use core::{fmt, str::FromStr};
+
+use crate::{Digit, Grid, StepOutcome, NB_CELL};
+
+/// A single event of a [SolveReplay]
+/// Unlike [StepOutcome], it holds everything needed to reproduce the state of the grid after it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayEvent {
+    /// The digit has been placed in the empty cell at this position
+    Placed(usize, Digit),
+    /// Every guessed cell after the position has been emptied, and the cell at the position now holds the digit
+    Backtracked(usize, Digit),
+    /// The grid is complete
+    Solved,
+}
+
+/// The ordered events of a solving session, which can be serialized and played back to reproduce
+/// every intermediate state of the grid
+///
+/// The text format holds one event per line:
+/// - `P <pos> <digit>` for [ReplayEvent::Placed]
+/// - `B <pos> <digit>` for [ReplayEvent::Backtracked]
+/// - `S` for [ReplayEvent::Solved]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveReplay {
+    events: Vec<ReplayEvent>,
+}
+
+/// A line of a serialized [SolveReplay] is not a valid event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayParseError {
+    /// Starting from 1
+    pub line: usize,
+}
+
+impl SolveReplay {
+    /// Solve [puzzle] step by step, until there is no more solution or [max_steps] have been made
+    pub fn record(puzzle: &Grid, max_steps: usize) -> SolveReplay {
+        let mut solver = puzzle.try_solve();
+        let mut events = Vec::new();
+        for _ in 0..max_steps {
+            let event = match solver.step() {
+                StepOutcome::Placed(pos, digit) => ReplayEvent::Placed(pos, digit),
+                StepOutcome::Backtracked(_, pos) => ReplayEvent::Backtracked(
+                    pos,
+                    solver.grid().data[pos].expect("the cell has just been incremented"),
+                ),
+                StepOutcome::Solved => ReplayEvent::Solved,
+                StepOutcome::Exhausted => break,
+            };
+            events.push(event);
+        }
+        SolveReplay { events }
+    }
+
+    pub fn events(&self) -> &[ReplayEvent] {
+        &self.events
+    }
+
+    /// The state of the grid after each event, starting from [puzzle], which must be the recorded puzzle
+    pub fn play<'a>(&'a self, puzzle: &'a Grid) -> impl Iterator<Item = Grid> + 'a {
+        self.events.iter().scan(puzzle.clone(), |grid, event| {
+            match *event {
+                ReplayEvent::Placed(pos, digit) => grid.data[pos] = Some(digit),
+                ReplayEvent::Backtracked(pos, digit) => {
+                    for guessed in (pos + 1..NB_CELL).filter(|i| puzzle.data[*i].is_none()) {
+                        grid.data[guessed] = None;
+                    }
+                    grid.data[pos] = Some(digit);
+                }
+                ReplayEvent::Solved => {}
+            }
+            Some(grid.clone())
+        })
+    }
+}
+
+impl fmt::Display for SolveReplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for event in &self.events {
+            match event {
+                ReplayEvent::Placed(pos, digit) => writeln!(f, "P {} {}", pos, digit.value())?,
+                ReplayEvent::Backtracked(pos, digit) => writeln!(f, "B {} {}", pos, digit.value())?,
+                ReplayEvent::Solved => writeln!(f, "S")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for SolveReplay {
+    type Err = ReplayParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let events = s
+            .lines()
+            .enumerate()
+            .map(|(index, line)| parse_event(line).ok_or(ReplayParseError { line: index + 1 }))
+            .collect::<Result<_, _>>()?;
+        Ok(SolveReplay { events })
+    }
+}
+
+fn parse_event(line: &str) -> Option<ReplayEvent> {
+    let mut words = line.split_whitespace();
+    let kind = words.next()?;
+    let event = if kind == "S" {
+        ReplayEvent::Solved
+    } else {
+        let pos = words.next()?.parse().ok()?;
+        let digit = Digit::new(words.next()?.parse().ok()?)?;
+        match kind {
+            "P" => ReplayEvent::Placed(pos, digit),
+            "B" => ReplayEvent::Backtracked(pos, digit),
+            _ => return None,
+        }
+    };
+    words.next().is_none().then_some(event)
+}
+
+// The fixtures are 4×4 grids
+#[cfg(all(test, feature = "size-4x4"))]
+mod test {
+    use crate::{Grid, ReplayParseError, SolveReplay, StepOutcome};
+
+    #[test]
+    fn replay_round_trip() {
+        let puzzle = Grid::from_hex_str("12..............").unwrap();
+
+        let replay = SolveReplay::record(&puzzle, usize::MAX);
+        let serialized = replay.to_string();
+        let deserialized = serialized.parse::<SolveReplay>().unwrap();
+        assert_eq!(deserialized, replay);
+
+        let mut solver = puzzle.try_solve();
+        let mut played = deserialized.play(&puzzle);
+        while solver.step() != StepOutcome::Exhausted {
+            assert_eq!(played.next().as_ref(), Some(solver.grid()));
+        }
+        assert_eq!(played.next(), None);
+    }
+
+    #[test]
+    fn parse_bad_replay() {
+        assert_eq!(
+            "P 0 1\nP 1\nS".parse::<SolveReplay>(),
+            Err(ReplayParseError { line: 2 })
+        );
+        assert_eq!(
+            "P 0 1\nX 1 2".parse::<SolveReplay>(),
+            Err(ReplayParseError { line: 2 })
+        );
+    }
+}
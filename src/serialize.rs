@@ -0,0 +1,54 @@
+use alloc::vec::Vec;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Digit, Grid, NB_CELL};
+
+/// A flat array of NB_CELL numbers in row-major order, 0 standing for an empty cell
+impl Serialize for Grid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.data.iter().map(|cell| cell.map_or(0, Digit::value)))
+    }
+}
+
+/// Reject out of range numbers and contradicting digits, like [Grid::try_from_u8s]
+impl<'de> Deserialize<'de> for Grid {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = Vec::<u8>::deserialize(deserializer)?;
+        let array: [u8; NB_CELL] = values.try_into().map_err(|values: Vec<u8>| {
+            de::Error::invalid_length(values.len(), &"one number per cell")
+        })?;
+        Grid::try_from_u8s(array).map_err(|e| de::Error::custom(format_args!("{:?}", e)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Grid, NB_CELL, NB_DIGIT};
+
+    #[test]
+    fn json_round_trip() {
+        let mut grid = Grid::empty().solve().unwrap().grid().clone();
+        grid.data[0] = None;
+        grid.data[NB_CELL - 1] = None;
+
+        let json = serde_json::to_string(&grid).unwrap();
+        assert!(json.starts_with("[0,"));
+        assert_eq!(serde_json::from_str::<Grid>(&json).unwrap(), grid);
+    }
+
+    #[test]
+    fn json_rejects_bad_grids() {
+        let mut values = vec![0; NB_CELL];
+        assert!(serde_json::from_str::<Grid>(&format!("{:?}", values)).is_ok());
+
+        values[0] = NB_DIGIT as u8 + 1;
+        assert!(serde_json::from_str::<Grid>(&format!("{:?}", values)).is_err());
+
+        values[0] = 1;
+        values[1] = 1;
+        assert!(serde_json::from_str::<Grid>(&format!("{:?}", values)).is_err());
+
+        assert!(serde_json::from_str::<Grid>(&format!("{:?}", &values[1..])).is_err());
+    }
+}
@@ -0,0 +1,208 @@
+use alloc::{format, string::String};
+use core::fmt::Write;
+
+use crate::{cell_index, AnnotatedCell, AnnotatedGrid, Digit, Grid, BLOCK_SIDE, NB_DIGIT};
+
+/// How [Grid::to_svg_with] and [AnnotatedGrid::to_svg_with] draw a grid
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgOptions {
+    /// Width and height of a cell, in pixels
+    pub cell_size: u32,
+    /// Width of the lines between cells, in pixels
+    /// The borders of the blocks and of the grid are twice as thick
+    pub line_width: f32,
+    /// CSS font family of the digits, like `serif` or `"DejaVu Sans", sans-serif`
+    pub font_family: String,
+    /// Height of the digits, as a fraction of the cell size
+    pub font_scale: f32,
+    /// CSS color of the digits found by the solver, see [AnnotatedGrid::to_svg_with]
+    /// The givens are black
+    pub solved_color: String,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        SvgOptions {
+            cell_size: 40,
+            line_width: 1.,
+            font_family: String::from("sans-serif"),
+            font_scale: 0.6,
+            solved_color: String::from("royalblue"),
+        }
+    }
+}
+
+/// Whether the line between cells before [line] and cells from [line] is the border of a block
+fn is_block_border(line: usize) -> bool {
+    line.is_multiple_of(BLOCK_SIDE)
+}
+
+/// Escape the characters which can not appear as is in an XML attribute value
+fn escape_attribute(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+impl Grid {
+    /// A standalone SVG image of the grid, for printing, drawn with the [SvgOptions::default]
+    /// Each digit is a `<text>`, drawn as a given, and empty cells are left blank
+    pub fn to_svg(&self) -> String {
+        self.to_svg_with(&SvgOptions::default())
+    }
+
+    /// Same as [Grid::to_svg], with the size and the font of [options], see [AnnotatedGrid::to_svg_with]
+    pub fn to_svg_with(&self, options: &SvgOptions) -> String {
+        self.annotate(self).to_svg_with(options)
+    }
+}
+
+impl AnnotatedGrid {
+    /// A standalone SVG image of the grid, drawn with the [SvgOptions::default], like [Grid::to_svg]
+    /// The digits found by the solver are drawn in [SvgOptions::solved_color], apart from the givens
+    pub fn to_svg(&self) -> String {
+        self.to_svg_with(&SvgOptions::default())
+    }
+
+    /// Same as [AnnotatedGrid::to_svg], with the size, the font and the colors of [options]
+    /// The lines and the digits are in their own `<g>` groups, so that other layers,
+    /// like pencil marks, can be added in between
+    pub fn to_svg_with(&self, options: &SvgOptions) -> String {
+        let mut svg = String::new();
+        self.write_svg(&mut svg, options)
+            .expect("writing to a String can not fail");
+        svg
+    }
+
+    fn write_svg(&self, svg: &mut impl Write, options: &SvgOptions) -> core::fmt::Result {
+        let cell = options.cell_size as f32;
+        let thick = 2. * options.line_width;
+        // The outer border is drawn around the cells, not over them
+        let margin = thick / 2.;
+        let grid_size = cell * NB_DIGIT as f32;
+        let size = grid_size + thick;
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{size}" height="{size}" viewBox="0 0 {size} {size}">"#
+        )?;
+        writeln!(
+            svg,
+            r#"<rect width="{size}" height="{size}" fill="white"/>"#
+        )?;
+
+        writeln!(svg, r#"<g stroke="black" stroke-linecap="square">"#)?;
+        for line in 0..=NB_DIGIT {
+            let offset = margin + cell * line as f32;
+            let (start, end) = (margin, margin + grid_size);
+            let width = if is_block_border(line) {
+                thick
+            } else {
+                options.line_width
+            };
+            writeln!(
+                svg,
+                r#"<line x1="{start}" y1="{offset}" x2="{end}" y2="{offset}" stroke-width="{width}"/>"#
+            )?;
+            writeln!(
+                svg,
+                r#"<line x1="{offset}" y1="{start}" x2="{offset}" y2="{end}" stroke-width="{width}"/>"#
+            )?;
+        }
+        writeln!(svg, "</g>")?;
+
+        writeln!(
+            svg,
+            r#"<g font-family="{}" font-size="{}" text-anchor="middle" dominant-baseline="central">"#,
+            escape_attribute(&options.font_family),
+            cell * options.font_scale,
+        )?;
+        for row in 0..NB_DIGIT {
+            for column in 0..NB_DIGIT {
+                let (d, fill) = match self.cells()[cell_index(row, column)] {
+                    AnnotatedCell::Given(d) => (d, String::new()),
+                    AnnotatedCell::Solved(d) => (
+                        d,
+                        format!(r#" fill="{}""#, escape_attribute(&options.solved_color)),
+                    ),
+                    AnnotatedCell::Empty => continue,
+                };
+                let x = margin + cell * (column as f32 + 0.5);
+                let y = margin + cell * (row as f32 + 0.5);
+                writeln!(
+                    svg,
+                    r#"<text x="{x}" y="{y}"{fill}>{}</text>"#,
+                    Digit::to_char(d)
+                )?;
+            }
+        }
+        writeln!(svg, "</g>")?;
+        writeln!(svg, "</svg>")
+    }
+}
+
+// The fixtures are 4×4 grids
+#[cfg(all(test, feature = "size-4x4"))]
+mod test {
+    use crate::{Digit, Grid, SvgOptions};
+
+    #[test]
+    fn svg() {
+        let grid = Grid::from_hex_str("12.4..12.143...1").unwrap();
+        let svg = grid.to_svg();
+        assert!(svg.starts_with(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="162" height="162" viewBox="0 0 162 162">"#
+        ));
+        assert!(svg.ends_with("</svg>\n"));
+        // Two lines per row and column boundary: the outer ones and the block borders are thick
+        assert_eq!(svg.matches("<line ").count(), 10);
+        assert_eq!(svg.matches(r#"stroke-width="2""#).count(), 6);
+        assert_eq!(svg.matches("<text ").count(), grid.clue_count());
+        assert!(svg.contains(r#"<text x="21" y="21">1</text>"#));
+        assert!(svg.contains(r#"<text x="141" y="141">1</text>"#));
+
+        assert_eq!(Grid::empty().to_svg().matches("<text ").count(), 0);
+    }
+
+    #[test]
+    fn svg_options() {
+        let grid = Grid::from_hex_str("1...............").unwrap();
+        let options = SvgOptions {
+            cell_size: 10,
+            font_family: String::from(r#""Fira Sans" & co"#),
+            ..SvgOptions::default()
+        };
+        let svg = grid.to_svg_with(&options);
+        assert!(svg.contains(r#"width="42""#));
+        assert!(svg.contains(r#"font-family="&quot;Fira Sans&quot; &amp; co" font-size="6""#));
+        assert!(svg.contains(r#"<text x="6" y="6">1</text>"#));
+    }
+
+    #[test]
+    fn svg_of_solution_over_puzzle() {
+        let puzzle = Grid::from_hex_str("12.4..12.143...1").unwrap();
+        let mut grid = puzzle.clone();
+        grid.data[2] = Digit::new(3);
+        let options = SvgOptions {
+            solved_color: String::from("red"),
+            ..SvgOptions::default()
+        };
+
+        let svg = grid.annotate(&puzzle).to_svg_with(&options);
+        assert_eq!(svg.matches("<text ").count(), puzzle.clue_count() + 1);
+        assert!(svg.contains(r#"<text x="21" y="21">1</text>"#));
+        assert!(svg.contains(r#"<text x="101" y="21" fill="red">3</text>"#));
+        assert_eq!(svg.matches("fill=\"red\"").count(), 1);
+
+        // Without a solver, every digit is a given
+        assert_eq!(grid.to_svg(), grid.annotate(&grid).to_svg());
+        assert!(!grid.to_svg().contains("royalblue"));
+    }
+}
@@ -0,0 +1,289 @@
+use alloc::vec::Vec;
+
+use itertools::Itertools;
+
+use crate::{
+    block_indices, column_indices, row_indices, CandidateGrid, Digit, Grid, Unit, BLOCK_SIDE,
+    NB_CELL, NB_DIGIT,
+};
+
+mod x_wing;
+
+impl Grid {
+    /// Fill every empty cell which can only hold a single digit, until there is no more such cell
+    /// Return how many cells have been filled
+    pub fn apply_naked_singles(&mut self) -> usize {
+        let mut filled = 0;
+        loop {
+            let filled_before = filled;
+            for pos in 0..NB_CELL {
+                if let Some(d) = self.naked_single_at(pos) {
+                    self.data[pos] = Some(d);
+                    filled += 1;
+                }
+            }
+            if filled == filled_before {
+                return filled;
+            }
+        }
+    }
+
+    /// The only digit which can go in the cell at [pos], if it is empty and has a single candidate
+    pub(crate) fn naked_single_at(&self, pos: usize) -> Option<Digit> {
+        if self.data[pos].is_some() {
+            return None;
+        }
+        Digit::all()
+            .filter(|d| self.can_accept_digit_at_pos(*d, pos))
+            .exactly_one()
+            .ok()
+    }
+
+    /// In every row, column and block, place each digit which can only go in a single cell of the unit,
+    /// until there is no more such digit
+    /// Return how many cells have been filled
+    pub fn apply_hidden_singles(&mut self) -> usize {
+        let mut filled = 0;
+        loop {
+            let filled_before = filled;
+            let units = [Unit::Row, Unit::Column, Unit::Block]
+                .into_iter()
+                .flat_map(|unit| (0..NB_DIGIT).map(unit));
+            for unit in units {
+                for (d, positions) in Digit::all().zip(self.unit_candidate_positions(unit)) {
+                    let [pos] = positions[..] else {
+                        continue;
+                    };
+                    // An earlier placement in the unit may have taken the cell, if the grid is unsolvable
+                    if self.data[pos].is_none() && self.can_accept_digit_at_pos(d, pos) {
+                        self.data[pos] = Some(d);
+                        filled += 1;
+                    }
+                }
+            }
+            if filled == filled_before {
+                return filled;
+            }
+        }
+    }
+}
+
+impl CandidateGrid {
+    /// When two cells of a row, a column or a block have the same two candidates, those two digits must go
+    /// in these two cells: rule them out from the other cells of the unit, until there is no more such pair
+    /// Return how many candidates have been ruled out
+    pub fn apply_naked_pairs(&mut self) -> usize {
+        let mut eliminated = 0;
+        loop {
+            let eliminated_before = eliminated;
+            for unit in CandidateGrid::units() {
+                let cells = unit.cells();
+                let pairs = cells
+                    .iter()
+                    .copied()
+                    .filter(|pos| self.masks[*pos].count_ones() == 2)
+                    .tuple_combinations()
+                    .filter(|(first, second)| self.masks[*first] == self.masks[*second])
+                    .collect_vec();
+                for (first, second) in pairs {
+                    let pair = self.candidates(first).collect_vec();
+                    for &pos in &cells {
+                        if pos == first || pos == second {
+                            continue;
+                        }
+                        for &d in &pair {
+                            if self.eliminate(d, pos) {
+                                eliminated += 1;
+                            }
+                        }
+                    }
+                }
+            }
+            if eliminated == eliminated_before {
+                return eliminated;
+            }
+        }
+    }
+
+    /// When the candidates of a digit in a block all lie in a single row or column, the digit must go in that line
+    /// within the block: rule it out from the rest of the line. Conversely, when the candidates of a digit
+    /// in a row or a column all lie in a single block, rule it out from the rest of the block
+    /// Repeat until nothing more can be ruled out, and return how many candidates have been ruled out
+    pub fn apply_pointing(&mut self) -> usize {
+        let block_of =
+            |pos: usize| pos / NB_DIGIT / BLOCK_SIDE * BLOCK_SIDE + pos % NB_DIGIT / BLOCK_SIDE;
+        let mut eliminated = 0;
+        loop {
+            let eliminated_before = eliminated;
+            for d in Digit::all() {
+                for block in 0..NB_DIGIT {
+                    let positions = self.candidate_positions(d, block_indices(block));
+                    if let Ok(row) = positions.iter().map(|pos| pos / NB_DIGIT).all_equal_value() {
+                        eliminated += self.eliminate_outside(d, row_indices(row), &positions);
+                    }
+                    if let Ok(column) = positions.iter().map(|pos| pos % NB_DIGIT).all_equal_value()
+                    {
+                        eliminated += self.eliminate_outside(d, column_indices(column), &positions);
+                    }
+                }
+                for line in 0..NB_DIGIT {
+                    for cells in [row_indices(line), column_indices(line)] {
+                        let positions = self.candidate_positions(d, cells);
+                        if let Ok(block) = positions.iter().copied().map(block_of).all_equal_value()
+                        {
+                            eliminated +=
+                                self.eliminate_outside(d, block_indices(block), &positions);
+                        }
+                    }
+                }
+            }
+            if eliminated == eliminated_before {
+                return eliminated;
+            }
+        }
+    }
+
+    /// The cells among [cells] where [d] is a candidate
+    fn candidate_positions(&self, d: Digit, cells: [usize; NB_DIGIT]) -> Vec<usize> {
+        cells
+            .into_iter()
+            .filter(|pos| self.is_candidate(d, *pos))
+            .collect()
+    }
+
+    /// Rule out [d] from [cells], except from [kept], and return how many candidates have been ruled out
+    fn eliminate_outside(&mut self, d: Digit, cells: [usize; NB_DIGIT], kept: &[usize]) -> usize {
+        cells
+            .into_iter()
+            .filter(|pos| !kept.contains(pos))
+            .filter(|pos| self.eliminate(d, *pos))
+            .count()
+    }
+}
+
+// The fixtures are 9×9 grids
+#[cfg(all(test, feature = "size-9x9"))]
+mod test {
+    use crate::Grid;
+
+    const PUZZLE: &str =
+        "53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79";
+
+    /// Singles get stuck on this one, until naked pairs rule out some candidates
+    const PAIRS_PUZZLE: &str =
+        "...3..95....49.........8....6791..3..54......1....24..5..8.3.9..4........39.2...7";
+
+    /// Singles and naked pairs get stuck on this one, until pointing rules out some candidates
+    const POINTING_PUZZLE: &str =
+        "36....9...9.5.2.....7....415....32.4...7...5......537..2.6.7.8......4..91........";
+
+    /// Singles, naked pairs and pointing get stuck on this one, and an X-Wing rules out some more candidates
+    const X_WING_PUZZLE: &str =
+        "...87.1.....6.2.....2....97..57.9....6...3.5.89......1.8..4..19...9.7..84.....3..";
+
+    #[test]
+    fn naked_singles_agree_with_solution() {
+        let mut grid = PUZZLE.parse::<Grid>().unwrap();
+        let solution = grid.solve().unwrap();
+
+        let filled = grid.apply_naked_singles();
+        assert!(filled > 0);
+        for (cell, solved) in grid.data.iter().zip(solution.grid().data) {
+            assert!(cell.is_none() || *cell == solved);
+        }
+        assert_eq!(grid.clue_count(), 30 + filled);
+    }
+
+    #[test]
+    fn hidden_singles_agree_with_solution() {
+        let mut grid = PUZZLE.parse::<Grid>().unwrap();
+        let solution = grid.solve().unwrap();
+
+        let filled = grid.apply_hidden_singles();
+        assert!(filled > 0);
+        for (cell, solved) in grid.data.iter().zip(solution.grid().data) {
+            assert!(cell.is_none() || *cell == solved);
+        }
+    }
+
+    #[test]
+    fn singles_solve_easy_puzzle() {
+        let mut grid = PUZZLE.parse::<Grid>().unwrap();
+        let solution = grid.solve().unwrap();
+        while grid.apply_naked_singles() + grid.apply_hidden_singles() > 0 {}
+        assert_eq!(&grid, solution.grid());
+    }
+
+    #[test]
+    fn naked_pairs_unlock_singles() {
+        let grid = PAIRS_PUZZLE.parse::<Grid>().unwrap();
+        let solution = grid.solve().unwrap();
+        let mut candidates = grid.candidates();
+
+        // Singles alone get stuck
+        candidates.apply_singles();
+        assert!(!candidates.grid().is_solved());
+        assert_eq!(candidates.apply_singles(), 0);
+
+        assert!(candidates.apply_naked_pairs() > 0);
+        assert!(candidates.apply_singles() > 0);
+        while candidates.apply_naked_pairs() + candidates.apply_singles() > 0 {}
+        assert_eq!(candidates.grid(), solution.grid());
+    }
+
+    #[test]
+    fn pointing_unlocks_singles() {
+        let grid = POINTING_PUZZLE.parse::<Grid>().unwrap();
+        let solution = grid.solve().unwrap();
+        let mut candidates = grid.candidates();
+
+        while candidates.apply_singles() + candidates.apply_naked_pairs() > 0 {}
+        assert!(!candidates.grid().is_solved());
+
+        assert!(candidates.apply_pointing() > 0);
+        assert!(candidates.apply_singles() > 0);
+        while candidates.apply_pointing()
+            + candidates.apply_naked_pairs()
+            + candidates.apply_singles()
+            > 0
+        {}
+        assert_eq!(candidates.grid(), solution.grid());
+    }
+
+    #[test]
+    fn x_wing_rules_out_candidates_other_techniques_can_not() {
+        let grid = X_WING_PUZZLE.parse::<Grid>().unwrap();
+        let solution = grid.solve().unwrap();
+        let mut candidates = grid.candidates();
+
+        while candidates.apply_pointing()
+            + candidates.apply_naked_pairs()
+            + candidates.apply_singles()
+            > 0
+        {}
+        assert!(!candidates.grid().is_solved());
+
+        assert_eq!(candidates.apply_x_wing(), 6);
+        assert_eq!(candidates.apply_x_wing(), 0);
+        // Only wrong candidates have been ruled out
+        for (pos, cell) in candidates.grid().data.iter().enumerate() {
+            let solved = solution.grid().data[pos].unwrap();
+            assert!(*cell == Some(solved) || candidates.is_candidate(solved, pos));
+        }
+    }
+
+    #[test]
+    fn naked_singles_on_complete_grid() {
+        let mut grid = PUZZLE
+            .parse::<Grid>()
+            .unwrap()
+            .solve()
+            .unwrap()
+            .grid()
+            .clone();
+        assert_eq!(grid.apply_naked_singles(), 0);
+
+        let mut grid = Grid::empty();
+        assert_eq!(grid.apply_naked_singles(), 0);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{column_indices, row_indices, CandidateGrid, Digit, NB_DIGIT};
+
+impl CandidateGrid {
+    /// When the candidates of a digit in two rows lie in the same two columns, the digit must go in two opposite
+    /// corners of this rectangle: rule it out from the rest of both columns
+    /// Likewise with the rows and columns swapped
+    /// Repeat until nothing more can be ruled out, and return how many candidates have been ruled out
+    pub fn apply_x_wing(&mut self) -> usize {
+        let mut eliminated = 0;
+        loop {
+            let eliminated_before = eliminated;
+            for d in Digit::all() {
+                eliminated += self.x_wing(d, row_indices, column_indices);
+                eliminated += self.x_wing(d, column_indices, row_indices);
+            }
+            if eliminated == eliminated_before {
+                return eliminated;
+            }
+        }
+    }
+
+    /// Look for the X-Wings of [d] whose two base lines are given by [base], and which rule [d] out
+    /// from the crossing lines given by [cross]
+    fn x_wing(
+        &mut self,
+        d: Digit,
+        base: fn(usize) -> [usize; NB_DIGIT],
+        cross: fn(usize) -> [usize; NB_DIGIT],
+    ) -> usize {
+        // For each base line, the crossing lines where d is a candidate, as a bit mask
+        let crossings: [u16; NB_DIGIT] = core::array::from_fn(|line| {
+            base(line)
+                .into_iter()
+                .enumerate()
+                .filter(|(_, pos)| self.is_candidate(d, *pos))
+                .fold(0, |mask, (crossing, _)| mask | 1 << crossing)
+        });
+
+        let mut eliminated = 0;
+        for first in 0..NB_DIGIT {
+            if crossings[first].count_ones() != 2 {
+                continue;
+            }
+            for second in first + 1..NB_DIGIT {
+                if crossings[second] != crossings[first] {
+                    continue;
+                }
+                let crossing_lines = (0..NB_DIGIT).filter(|line| crossings[first] & 1 << line != 0);
+                for crossing in crossing_lines {
+                    for (line, pos) in cross(crossing).into_iter().enumerate() {
+                        if line != first && line != second && self.eliminate(d, pos) {
+                            eliminated += 1;
+                        }
+                    }
+                }
+            }
+        }
+        eliminated
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{Digit, Grid, BLOCK_SIDE, NB_DIGIT};
+
+/// The digits given to [Grid::relabel] are not a permutation: [repeated] appears more than once
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotAPermutation {
+    pub repeated: Digit,
+}
+
+/// Why a permutation of rows or columns within a band or a stack, or of the bands or stacks, was refused
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BandPermutationError {
+    /// There are only [BLOCK_SIDE] bands and stacks
+    NoSuchBand(usize),
+    /// Not below [BLOCK_SIDE]: the row or column would leave its band or stack
+    OutOfBand(usize),
+    /// The index appears more than once in the permutation
+    Repeated(usize),
+}
+
+/// Check that [perm] holds every index of a band or a stack exactly once
+fn check_band_permutation(perm: &[usize; BLOCK_SIDE]) -> Result<(), BandPermutationError> {
+    for (i, &index) in perm.iter().enumerate() {
+        if index >= BLOCK_SIDE {
+            return Err(BandPermutationError::OutOfBand(index));
+        }
+        if perm[..i].contains(&index) {
+            return Err(BandPermutationError::Repeated(index));
+        }
+    }
+    Ok(())
+}
+
+/// Check that [band] is the index of a band or a stack
+fn check_band(band: usize) -> Result<(), BandPermutationError> {
+    if band < BLOCK_SIDE {
+        Ok(())
+    } else {
+        Err(BandPermutationError::NoSuchBand(band))
+    }
+}
+
+impl Grid {
+    /// The grid turned a quarter clockwise: the first column, read upwards, becomes the first row
+    pub fn rotate90(&self) -> Grid {
+        self.permuted(|row, column| (NB_DIGIT - 1 - column, row))
+    }
+
+    /// The grid seen in a mirror on its side: the columns are in the reverse order
+    pub fn reflect_horizontal(&self) -> Grid {
+        self.permuted(|row, column| (row, NB_DIGIT - 1 - column))
+    }
+
+    /// The grid flipped along its main diagonal: the rows become the columns
+    pub fn transpose(&self) -> Grid {
+        self.permuted(|row, column| (column, row))
+    }
+
+    /// The grid with every digit `d` replaced by `perm[d - 1]`, which keeps a solved grid solved
+    /// Fails if a digit appears twice in [perm]
+    pub fn relabel(&self, perm: &[Digit; NB_DIGIT]) -> Result<Grid, NotAPermutation> {
+        for (i, d) in perm.iter().enumerate() {
+            if perm[..i].contains(d) {
+                return Err(NotAPermutation { repeated: *d });
+            }
+        }
+        Ok(Grid {
+            data: self
+                .data
+                .map(|cell| cell.map(|d| perm[usize::from(d.value() - 1)])),
+        })
+    }
+
+    /// The grid with the rows of [band] reordered: its row `i` is the row `perm[i]` of the band in self
+    /// The other bands are left untouched
+    pub fn permute_rows_in_band(
+        &self,
+        band: usize,
+        perm: &[usize; BLOCK_SIDE],
+    ) -> Result<Grid, BandPermutationError> {
+        check_band(band)?;
+        check_band_permutation(perm)?;
+        Ok(self.permuted(|row, column| match row / BLOCK_SIDE == band {
+            true => (band * BLOCK_SIDE + perm[row % BLOCK_SIDE], column),
+            false => (row, column),
+        }))
+    }
+
+    /// The grid with the columns of [stack] reordered, like [Grid::permute_rows_in_band]
+    pub fn permute_columns_in_stack(
+        &self,
+        stack: usize,
+        perm: &[usize; BLOCK_SIDE],
+    ) -> Result<Grid, BandPermutationError> {
+        check_band(stack)?;
+        check_band_permutation(perm)?;
+        Ok(
+            self.permuted(|row, column| match column / BLOCK_SIDE == stack {
+                true => (row, stack * BLOCK_SIDE + perm[column % BLOCK_SIDE]),
+                false => (row, column),
+            }),
+        )
+    }
+
+    /// The grid with the bands reordered: its band `i` is the band `perm[i]` of self
+    pub fn permute_bands(&self, perm: &[usize; BLOCK_SIDE]) -> Result<Grid, BandPermutationError> {
+        check_band_permutation(perm)?;
+        Ok(self.permuted(|row, column| {
+            (
+                perm[row / BLOCK_SIDE] * BLOCK_SIDE + row % BLOCK_SIDE,
+                column,
+            )
+        }))
+    }
+
+    /// The grid with the stacks reordered, like [Grid::permute_bands]
+    pub fn permute_stacks(&self, perm: &[usize; BLOCK_SIDE]) -> Result<Grid, BandPermutationError> {
+        check_band_permutation(perm)?;
+        Ok(self.permuted(|row, column| {
+            (
+                row,
+                perm[column / BLOCK_SIDE] * BLOCK_SIDE + column % BLOCK_SIDE,
+            )
+        }))
+    }
+
+    /// A grid with the cells moved around: the cell at (row, column) is taken from [source] (row, column) in self
+    /// The permutations of the sudoku symmetry group keep a valid grid valid, and a solvable one solvable
+    fn permuted(&self, source: impl Fn(usize, usize) -> (usize, usize)) -> Grid {
+        let mut grid = Grid::empty();
+        for row in 0..NB_DIGIT {
+            for column in 0..NB_DIGIT {
+                grid[(row, column)] = self[source(row, column)];
+            }
+        }
+        grid
+    }
+}
+
+// The fixtures are 4×4 grids
+#[cfg(all(test, feature = "size-4x4"))]
+mod test {
+    use crate::{BandPermutationError, Digit, Grid, NotAPermutation};
+
+    #[test]
+    fn rotate() {
+        let grid = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        assert_eq!(grid.rotate90().to_line_string(), "...1.1.2.41.1324");
+        assert_eq!(grid.rotate90(), grid.transpose().reflect_horizontal());
+
+        let solution = grid.solve().unwrap().grid;
+        let mut rotated = solution.clone();
+        for _ in 0..4 {
+            rotated = rotated.rotate90();
+            assert!(rotated.is_solved());
+        }
+        assert_eq!(rotated, solution);
+    }
+
+    #[test]
+    fn reflect_and_transpose() {
+        let grid = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        assert_eq!(
+            grid.reflect_horizontal().to_line_string(),
+            "4.2121..341.1..."
+        );
+        assert_eq!(grid.transpose().to_line_string(), "1...2.1..14.4231");
+        assert_eq!(grid.reflect_horizontal().reflect_horizontal(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+        assert_eq!(
+            grid.transpose().count_solutions(None),
+            grid.count_solutions(None)
+        );
+    }
+
+    #[test]
+    fn relabel() {
+        let d = |v| Digit::new(v).unwrap();
+        let grid = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        let swap_1_and_2 = [d(2), d(1), d(3), d(4)];
+        assert_eq!(
+            grid.relabel(&swap_1_and_2).unwrap().to_line_string(),
+            "21.4..21.243...2"
+        );
+
+        let solution = grid.solve().unwrap().grid;
+        let relabeled = solution.relabel(&[d(3), d(4), d(2), d(1)]).unwrap();
+        assert!(relabeled.is_solved());
+        assert_ne!(relabeled, solution);
+
+        assert_eq!(
+            grid.relabel(&[d(1), d(2), d(1), d(4)]),
+            Err(NotAPermutation { repeated: d(1) })
+        );
+    }
+
+    #[test]
+    fn permute_bands_and_stacks() {
+        let grid = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        assert_eq!(
+            grid.permute_rows_in_band(1, &[1, 0])
+                .unwrap()
+                .to_line_string(),
+            "12.4..12...1.143"
+        );
+        assert_eq!(
+            grid.permute_columns_in_stack(0, &[1, 0])
+                .unwrap()
+                .to_line_string(),
+            "21.4..121.43...1"
+        );
+        assert_eq!(
+            grid.permute_bands(&[1, 0]).unwrap().to_line_string(),
+            ".143...112.4..12"
+        );
+        assert_eq!(
+            grid.permute_stacks(&[1, 0]).unwrap().to_line_string(),
+            ".41212..43.1.1.."
+        );
+        assert_eq!(grid.permute_bands(&[0, 1]).unwrap(), grid);
+
+        let solution = grid.solve().unwrap().grid;
+        let permuted = solution
+            .permute_rows_in_band(0, &[1, 0])
+            .and_then(|g| g.permute_stacks(&[1, 0]))
+            .unwrap();
+        assert!(permuted.is_solved());
+
+        assert_eq!(
+            grid.permute_rows_in_band(2, &[1, 0]),
+            Err(BandPermutationError::NoSuchBand(2))
+        );
+        assert_eq!(
+            grid.permute_bands(&[0, 2]),
+            Err(BandPermutationError::OutOfBand(2))
+        );
+        assert_eq!(
+            grid.permute_stacks(&[1, 1]),
+            Err(BandPermutationError::Repeated(1))
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{Cell, Digit, Grid, SolvedGrid, BLOCK_SIDE, NB_DIGIT};
+
+/// A group of NB_DIGIT cells which must hold every digit exactly once
+/// Rows, columns and blocks are numbered from 0, top to bottom and left to right
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    Row(usize),
+    Column(usize),
+    Block(usize),
+}
+
+impl Unit {
+    /// Index of every cell of the unit, in row-major order
+    pub fn cells(self) -> [usize; NB_DIGIT] {
+        match self {
+            Unit::Row(row) => row_indices(row),
+            Unit::Column(column) => column_indices(column),
+            Unit::Block(block) => block_indices(block),
+        }
+    }
+}
+
+/// Index of every cell of the row, from left to right
+pub fn row_indices(row: usize) -> [usize; NB_DIGIT] {
+    core::array::from_fn(|i| row * NB_DIGIT + i)
+}
+
+/// Index of every cell of the column, from top to bottom
+pub fn column_indices(column: usize) -> [usize; NB_DIGIT] {
+    core::array::from_fn(|i| i * NB_DIGIT + column)
+}
+
+/// Index of every cell of the block, in row-major order
+/// Blocks are numbered in row-major order too
+pub fn block_indices(block: usize) -> [usize; NB_DIGIT] {
+    let first_row = block / BLOCK_SIDE * BLOCK_SIDE;
+    let first_column = block % BLOCK_SIDE * BLOCK_SIDE;
+    core::array::from_fn(|i| {
+        (first_row + i / BLOCK_SIDE) * NB_DIGIT + first_column + i % BLOCK_SIDE
+    })
+}
+
+impl Grid {
+    /// For each digit (the digit `d` at index `d - 1`), the positions of the empty cells of [unit] where it can go
+    /// A digit already present in the unit can go nowhere else in it
+    pub fn unit_candidate_positions(&self, unit: Unit) -> [Vec<usize>; NB_DIGIT] {
+        let cells = unit.cells();
+        let mut positions: [Vec<usize>; NB_DIGIT] = Default::default();
+        for (d, positions) in Digit::all().zip(positions.iter_mut()) {
+            *positions = cells
+                .iter()
+                .copied()
+                .filter(|&pos| self.data[pos].is_none() && self.can_accept_digit_at_pos(d, pos))
+                .collect();
+        }
+        positions
+    }
+
+    /// The cells of each row, from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = [Cell; NB_DIGIT]> + '_ {
+        self.units_cells(Unit::Row)
+    }
+
+    /// The cells of each column, from left to right
+    pub fn columns(&self) -> impl Iterator<Item = [Cell; NB_DIGIT]> + '_ {
+        self.units_cells(Unit::Column)
+    }
+
+    /// The cells of each block, in row-major order, see [block_indices]
+    pub fn blocks(&self) -> impl Iterator<Item = [Cell; NB_DIGIT]> + '_ {
+        self.units_cells(Unit::Block)
+    }
+
+    fn units_cells(&self, unit: fn(usize) -> Unit) -> impl Iterator<Item = [Cell; NB_DIGIT]> + '_ {
+        (0..NB_DIGIT).map(move |i| unit(i).cells().map(|pos| self.data[pos]))
+    }
+
+    /// Number of filled cells in each row, from top to bottom
+    pub fn givens_per_row(&self) -> [usize; NB_DIGIT] {
+        self.givens_per_unit(Unit::Row)
+    }
+
+    /// Number of filled cells in each column, from left to right
+    pub fn givens_per_col(&self) -> [usize; NB_DIGIT] {
+        self.givens_per_unit(Unit::Column)
+    }
+
+    /// Number of filled cells in each block, in row-major order
+    pub fn givens_per_block(&self) -> [usize; NB_DIGIT] {
+        self.givens_per_unit(Unit::Block)
+    }
+
+    fn givens_per_unit(&self, unit: fn(usize) -> Unit) -> [usize; NB_DIGIT] {
+        let mut units = self.units_cells(unit);
+        core::array::from_fn(|_| {
+            let cells = units.next().expect("there are NB_DIGIT units of each kind");
+            cells.iter().flatten().count()
+        })
+    }
+}
+
+impl SolvedGrid {
+    /// The digits of every row, then every column, then every block, each as a string
+    /// Meant for assertion messages, to see at a glance which unit is wrong
+    pub fn unit_strings(&self) -> Vec<(Unit, String)> {
+        [Unit::Row, Unit::Column, Unit::Block]
+            .into_iter()
+            .flat_map(|unit| (0..NB_DIGIT).map(unit))
+            .map(|unit| {
+                let digits = unit
+                    .cells()
+                    .iter()
+                    .map(|&pos| self.grid.data[pos].map_or('.', Digit::to_char))
+                    .collect();
+                (unit, digits)
+            })
+            .collect()
+    }
+}
+
+// The fixtures are 4×4 grids
+#[cfg(all(test, feature = "size-4x4"))]
+mod test {
+    use crate::{block_indices, column_indices, row_indices, Digit, Grid, Unit, NB_DIGIT};
+
+    #[test]
+    fn solution_unit_strings() {
+        let solution = Grid::empty().assert_solvable().unwrap();
+        let strings = solution.unit_strings();
+
+        assert_eq!(strings.len(), 12);
+        assert_eq!(strings[0], (Unit::Row(0), "1234".to_string()));
+        assert_eq!(strings[5], (Unit::Column(1), "2413".to_string()));
+        assert_eq!(strings[11], (Unit::Block(3), "4321".to_string()));
+    }
+
+    #[test]
+    fn units_of_a_grid() {
+        let grid = Grid::from_hex_str("12.4..12.1430..1").unwrap();
+        let d = |v| Digit::new(v);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), grid.to_grid_2d().to_vec());
+        assert_eq!(grid.columns().nth(2).unwrap(), [None, d(1), d(4), None]);
+        assert_eq!(grid.blocks().nth(1).unwrap(), [None, d(4), d(1), d(2)]);
+
+        // In a solved grid, every unit holds every digit once
+        let solution = grid.solve().unwrap();
+        for units in [
+            solution.grid().rows().collect::<Vec<_>>(),
+            solution.grid().columns().collect(),
+            solution.grid().blocks().collect(),
+        ] {
+            assert_eq!(units.len(), NB_DIGIT);
+            for cells in units {
+                let mut digits = cells.map(|cell| cell.unwrap().value());
+                digits.sort();
+                assert_eq!(digits, [1, 2, 3, 4]);
+            }
+        }
+    }
+
+    #[test]
+    fn unit_cells() {
+        assert_eq!(Unit::Row(1).cells(), [4, 5, 6, 7]);
+        assert_eq!(Unit::Column(2).cells(), [2, 6, 10, 14]);
+        assert_eq!(Unit::Block(3).cells(), [10, 11, 14, 15]);
+        assert_eq!(block_indices(1), [2, 3, 6, 7]);
+        assert_eq!(row_indices(3), [12, 13, 14, 15]);
+        assert_eq!(column_indices(0), [0, 4, 8, 12]);
+    }
+
+    #[test]
+    fn candidate_positions_in_partially_filled_unit() {
+        #[rustfmt::skip]
+        let grid = Grid::from_u8s([
+            1, 0, 0, 0,
+            0, 0, 2, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 3,
+        ]);
+
+        assert_eq!(
+            grid.unit_candidate_positions(Unit::Row(0)),
+            [vec![], vec![1], vec![1, 2], vec![1, 2, 3]]
+        );
+        assert_eq!(
+            grid.unit_candidate_positions(Unit::Block(1)),
+            [vec![7], vec![], vec![2], vec![2, 3, 7]]
+        );
+    }
+
+    #[test]
+    fn givens_per_unit() {
+        #[rustfmt::skip]
+        let grid = Grid::from_u8s([
+            1, 2, 0, 0,
+            0, 0, 0, 1,
+            2, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+
+        assert_eq!(grid.givens_per_row(), [2, 1, 1, 0]);
+        assert_eq!(grid.givens_per_col(), [2, 1, 0, 1]);
+        assert_eq!(grid.givens_per_block(), [2, 1, 1, 0]);
+    }
+}
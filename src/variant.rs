@@ -0,0 +1,94 @@
+use crate::{Constraint, Digit, Grid, GridSolver, NB_DIGIT};
+
+/// A variant of the sudoku, with rules on top of the usual ones, see [Grid::try_solve_variant]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Variant {
+    /// The usual rules only, as with [Grid::try_solve]
+    Standard,
+    /// X-Sudoku: each of the two main diagonals also holds every digit once
+    Diagonal,
+}
+
+/// X-Sudoku: each of the two main diagonals also holds every digit once, see [Variant::Diagonal]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiagonalConstraint;
+
+impl Constraint for DiagonalConstraint {
+    fn allows(&self, grid: &Grid, pos: usize, d: Digit) -> bool {
+        grid.diagonals_accept(d, pos)
+    }
+}
+
+impl Grid {
+    /// Same as [Grid::try_solve], but the solutions also follow the rules of [variant]
+    /// Givens which already break them leave no solution
+    pub fn try_solve_variant(&self, variant: Variant) -> GridSolver<'_> {
+        match variant {
+            Variant::Standard => GridSolver::from_grid(self),
+            Variant::Diagonal => self.try_solve_with_constraints(&[&DiagonalConstraint]),
+        }
+    }
+
+    /// Whether no other cell of the diagonals through [pos], if any, holds [d]
+    fn diagonals_accept(&self, d: Digit, pos: usize) -> bool {
+        let (row, column) = (pos / NB_DIGIT, pos % NB_DIGIT);
+        let main = (row == column).then(|| (0..NB_DIGIT).map(|i| i * NB_DIGIT + i));
+        let anti = (row + column == NB_DIGIT - 1)
+            .then(|| (0..NB_DIGIT).map(|i| i * NB_DIGIT + NB_DIGIT - 1 - i));
+        main.into_iter()
+            .flatten()
+            .chain(anti.into_iter().flatten())
+            .all(|other| other == pos || self.data[other] != Some(d))
+    }
+}
+
+// The fixtures are 4×4 grids
+#[cfg(all(test, feature = "size-4x4"))]
+mod test {
+    use crate::{Grid, Variant};
+
+    #[test]
+    fn diagonal_puzzle() {
+        let puzzle = Grid::from_hex_str("....43.1.4......").unwrap();
+        // The first solution of the plain rules breaks the diagonals
+        assert_eq!(
+            puzzle.solve().unwrap().grid().to_line_string(),
+            "1234432124133142"
+        );
+        assert_eq!(puzzle.count_solutions(None), 6);
+
+        let solutions = puzzle
+            .try_solve_variant(Variant::Diagonal)
+            .collect::<Vec<_>>();
+        assert_eq!(solutions.len(), 1);
+        assert_eq!(solutions[0].grid().to_line_string(), "2143432134121234");
+        assert_eq!(
+            puzzle.try_solve_variant(Variant::Standard).count(),
+            puzzle.count_solutions(None)
+        );
+    }
+
+    #[test]
+    fn givens_break_the_diagonal() {
+        let puzzle = Grid::from_hex_str("1.........1.....").unwrap();
+        assert!(puzzle.solve().is_some());
+        let mut solver = puzzle.try_solve_variant(Variant::Diagonal);
+        assert_eq!(solver.next(), None);
+        solver.reset();
+        assert_eq!(solver.next(), None);
+    }
+
+    #[test]
+    fn empty_grid() {
+        for solution in Grid::empty().try_solve_variant(Variant::Diagonal) {
+            for pos in 0..crate::NB_CELL {
+                let d = solution.grid().data[pos].unwrap();
+                assert!(solution.grid().diagonals_accept(d, pos));
+            }
+        }
+        assert_eq!(
+            Grid::empty().try_solve_variant(Variant::Diagonal).count(),
+            48
+        );
+    }
+}
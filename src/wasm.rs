@@ -0,0 +1,35 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::Grid;
+
+/// Solve a puzzle given in the single line format of [Grid::from_hex_str], and return its first solution
+/// in the same format, or None (undefined in JavaScript) if the line can not be parsed or has no solution
+/// Nothing here can panic, as a panic would abort the whole WebAssembly module
+#[wasm_bindgen]
+pub fn solve_line(puzzle: &str) -> Option<String> {
+    let grid = puzzle.parse::<Grid>().ok()?;
+    Some(grid.solve()?.grid().to_line_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::solve_line;
+    use crate::Grid;
+
+    #[test]
+    fn solve_a_line() {
+        let solution = solve_line(&Grid::empty().to_line_string()).unwrap();
+        assert!(solution.parse::<Grid>().unwrap().is_solved());
+        assert_eq!(solve_line(&solution), Some(solution));
+    }
+
+    #[test]
+    fn invalid_lines() {
+        assert_eq!(solve_line(""), None);
+        assert_eq!(solve_line("not a sudoku"), None);
+        assert_eq!(solve_line(&"é".repeat(crate::NB_CELL)), None);
+        // Two 1s in the first row
+        let contradiction = format!("11{}", &Grid::empty().to_line_string()[2..]);
+        assert_eq!(solve_line(&contradiction), None);
+    }
+}
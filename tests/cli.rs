@@ -0,0 +1,187 @@
+//! Run the command line tool as a user would
+#![cfg(all(feature = "std", feature = "size-9x9"))]
+
+use std::{
+    io::{ErrorKind, Write},
+    process::{Command, Output, Stdio},
+};
+
+const PUZZLE: &str =
+    "53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79";
+const SOLUTION: &str =
+    "534678912672195348198342567859761423426853791713924856961537284287419635345286179";
+
+/// Run the tool with [args], feeding [stdin] to it
+/// The tool may exit without reading its standard input, like on a usage error: the pipe is then broken
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_sudoku-rs"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let written = child.stdin.take().unwrap().write_all(stdin.as_bytes());
+    if let Err(e) = written {
+        assert_eq!(e.kind(), ErrorKind::BrokenPipe, "{}", e);
+    }
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn solve_puzzle_given_as_text() {
+    let output = run(&["--grid", PUZZLE], "");
+    assert!(output.status.success());
+    let solution = SOLUTION.parse::<sudoku_rs::Grid>().unwrap();
+    assert_eq!(stdout(&output), solution.to_string());
+}
+
+#[test]
+fn solve_puzzle_from_stdin() {
+    let output = run(&[], &format!("{}\n", PUZZLE));
+    assert!(output.status.success());
+    assert_eq!(stdout(&run(&["--stdin"], PUZZLE)), stdout(&output));
+    assert_eq!(stdout(&run(&["--count", "-"], PUZZLE)), "1\n");
+}
+
+#[test]
+fn grid_text_must_be_flagged() {
+    // Without --grid, the argument is a path, even when it looks like a grid
+    let output = run(&[PUZZLE], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .starts_with(&format!("Cannot read {}", PUZZLE)));
+    assert_eq!(run(&["--grid"], "").status.code(), Some(2));
+}
+
+#[test]
+fn verify_from_stdin() {
+    let output = run(&["verify", "--grid", PUZZLE, "-"], SOLUTION);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "OK\n");
+    assert_eq!(
+        run(&["verify", "-", "--grid", SOLUTION], PUZZLE)
+            .status
+            .code(),
+        Some(0)
+    );
+
+    // The standard input can not hold both grids
+    let output = run(
+        &["verify", "-", "-"],
+        &format!("{}\n{}\n", PUZZLE, SOLUTION),
+    );
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stdout(&output).is_empty());
+}
+
+#[test]
+fn exit_codes() {
+    // The first row misses a 9, which the last column already holds
+    let unsolvable = format!("12345678.........9{}", ".".repeat(63));
+    assert_eq!(run(&["--grid", &unsolvable], "").status.code(), Some(1));
+    assert_eq!(
+        run(&["--all", "--count", "--grid", PUZZLE], "")
+            .status
+            .code(),
+        Some(2)
+    );
+    assert_eq!(run(&["--grid", "12345"], "").status.code(), Some(2));
+    assert_eq!(
+        run(&["verify", "--grid", PUZZLE, "--grid", SOLUTION], "")
+            .status
+            .code(),
+        Some(0)
+    );
+}
+
+#[test]
+fn verify_wrong_solution() {
+    // The first two digits are swapped: both givens are changed, and each digit is now twice in its column
+    let wrong = format!("35{}", &SOLUTION[2..]);
+    let output = run(&["verify", "--grid", PUZZLE, "--grid", &wrong], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "row 1, column 1: changes a given\n\
+         row 1, column 2: changes a given\n\
+         row 4, column 2: conflicts with another digit\n\
+         row 9, column 1: conflicts with another digit\n"
+    );
+
+    // A digit of the solution is erased, and the next cell, which is not a given, holds a 4 instead of a 6,
+    // which is also in its block and its column
+    let mut wrong = SOLUTION.to_string();
+    wrong.replace_range(2..4, ".4");
+    let output = run(&["verify", "--grid", PUZZLE, "--grid", &wrong], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "row 1, column 3: empty\n\
+         row 1, column 4: conflicts with another digit\n\
+         row 3, column 5: conflicts with another digit\n\
+         row 8, column 4: conflicts with another digit\n"
+    );
+}
+
+#[test]
+fn all_solutions() {
+    let output = run(&["--all", "--grid", PUZZLE], "");
+    assert!(output.status.success());
+    let solution = SOLUTION.parse::<sudoku_rs::Grid>().unwrap();
+    assert_eq!(stdout(&output), format!("{}1 solution(s)\n", solution));
+}
+
+#[test]
+fn limit_the_number_of_solutions() {
+    let empty = ".".repeat(81);
+    let output = run(&["--all", "--limit", "3", "--grid", &empty], "");
+    assert!(output.status.success());
+    let printed = stdout(&output);
+    assert!(printed.ends_with("\n3 solution(s)\n"), "{}", printed);
+
+    // The first solutions of the search, in its order
+    let expected = empty
+        .parse::<sudoku_rs::Grid>()
+        .unwrap()
+        .try_solve()
+        .take(3)
+        .map(|solution| solution.to_string())
+        .collect::<String>();
+    assert_eq!(printed, format!("{}3 solution(s)\n", expected));
+
+    assert_eq!(
+        stdout(&run(&["--count", "--limit", "5", "--grid", &empty], "")),
+        "5\n"
+    );
+}
+
+#[test]
+fn generate_with_seed() {
+    let output = run(&["generate", "--seed", "7"], "");
+    assert!(output.status.success());
+    let puzzle = stdout(&output);
+    assert_eq!(stdout(&run(&["generate", "--seed", "7"], "")), puzzle);
+    assert_ne!(stdout(&run(&["generate", "--seed", "8"], "")), puzzle);
+
+    let output = run(&["generate", "--seed", "7", "--with-solution"], "");
+    assert!(output.status.success());
+    let printed = stdout(&output);
+    let [line, solution_line] = printed.lines().collect::<Vec<_>>()[..] else {
+        panic!("expected the puzzle and its solution: {}", printed);
+    };
+    assert_eq!(format!("{}\n", line), puzzle);
+    let puzzle = line.parse::<sudoku_rs::Grid>().unwrap();
+    let solution = solution_line.parse::<sudoku_rs::Grid>().unwrap();
+    assert!(solution.is_solved());
+    assert!(puzzle
+        .check_solution(&sudoku_rs::parse_cells(solution_line).unwrap())
+        .is_empty());
+    assert!(puzzle.has_unique_solution());
+    assert_eq!(puzzle.solve().unwrap().grid(), &solution);
+}
@@ -0,0 +1,54 @@
+//! Check that enumerating solutions does not retain memory per solution
+//! This needs its own test binary, as it replaces the global allocator
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use sudoku_rs::Grid;
+
+/// Counts the bytes allocated and not yet freed by the current thread, so that other tests running
+/// in parallel do not interfere
+struct CountingAllocator;
+
+thread_local! {
+    static LIVE_BYTES: Cell<isize> = const { Cell::new(0) };
+}
+
+fn track(delta: isize) {
+    // The thread local may already be destroyed when the thread exits
+    let _ = LIVE_BYTES.try_with(|live| live.set(live.get() + delta));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        track(layout.size() as isize);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        track(-(layout.size() as isize));
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn enumerating_solutions_uses_constant_memory() {
+    let grid = Grid::empty();
+    let mut solver = grid.try_solve();
+
+    solver.next().expect("the empty grid has solutions");
+    let live_after_first = LIVE_BYTES.with(Cell::get);
+
+    let mut count = 1;
+    // Larger grids have far too many solutions to enumerate them all
+    for _solution in solver.take(1000) {
+        count += 1;
+        assert!(LIVE_BYTES.with(Cell::get) <= live_after_first);
+    }
+    assert!(count > 1);
+}
@@ -0,0 +1,27 @@
+//! Solve a puzzle through the public API only, as a dependent crate would
+#![cfg(feature = "size-9x9")]
+
+use sudoku_rs::Grid;
+
+#[test]
+fn solve_puzzle() {
+    let puzzle =
+        "53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79"
+            .parse::<Grid>()
+            .unwrap();
+    assert!(puzzle.has_unique_solution());
+    let solution = puzzle.solve().unwrap();
+    assert_eq!(
+        solution.grid().to_line_string(),
+        "534678912672195348198342567859761423426853791713924856961537284287419635345286179"
+    );
+}
+
+#[test]
+fn solve_unsolvable_puzzle() {
+    // The first row misses a 9, which the last column already holds
+    let puzzle = format!("12345678.........9{}", ".".repeat(63))
+        .parse::<Grid>()
+        .unwrap();
+    assert!(puzzle.solve().is_none());
+}